@@ -1,18 +1,43 @@
-use gloo_file::{callbacks::FileReader, File, FileList};
+use std::convert::TryInto;
+
+use gloo_file::{callbacks::FileReader, File, FileList, FileReadError, ObjectUrl};
 use wasm_bindgen::JsCast;
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlAnchorElement, HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
-use crate::stardew;
+use stardew_save::{SaveGame, SaveGameInfo};
+
+use crate::{
+    bundles, diff,
+    editor::{self, Edit},
+    monsters, museum, perfection,
+    upload::{self, SaveFiles},
+};
 
 pub struct App {
-    upload_task: Option<FileReader>,
-    content: Option<String>,
+    upload_tasks: Vec<FileReader>,
+    file_name: String,
+    source: String,
+    save_game: Option<Result<SaveGame, String>>,
+    info: Option<Result<SaveGameInfo, String>>,
+    compare_task: Option<FileReader>,
+    compare_file_name: String,
+    compare: Option<Result<SaveGame, String>>,
+    warnings: Vec<String>,
+    farmer: usize,
+    download: Option<ObjectUrl>,
+    download_error: Option<String>,
 }
 
 pub enum Msg {
-    File(File),
-    Loaded(String),
+    Files(Vec<File>),
+    Loaded(Result<Vec<u8>, String>),
+    InfoLoaded(Result<String, String>),
+    SelectFarmer(usize),
+    CompareFiles(Vec<File>),
+    CompareLoaded(Result<Vec<u8>, String>),
+    Edit(Edit),
+    Download,
 }
 
 impl Component for App {
@@ -21,69 +46,194 @@ impl Component for App {
 
     fn create(_ctx: &Context<Self>) -> Self {
         Self {
-            upload_task: None,
-            content: None,
+            upload_tasks: Vec::new(),
+            file_name: String::new(),
+            source: String::new(),
+            save_game: None,
+            info: None,
+            compare_task: None,
+            compare_file_name: String::new(),
+            compare: None,
+            warnings: Vec::new(),
+            farmer: 0,
+            download: None,
+            download_error: None,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            Msg::File(file) => {
-                let callback = ctx.link().callback(Msg::Loaded);
-                let task = gloo_file::callbacks::read_as_text(&file, move |res| {
-                    callback.emit(res.unwrap())
-                });
-                self.upload_task = Some(task);
+            Msg::Files(files) => {
+                let files = SaveFiles::new(files, File::name);
+                let save = match files.save {
+                    Some(save) => save,
+                    None => return false,
+                };
+
+                self.upload_tasks = vec![read_bytes(ctx, &save, Msg::Loaded)];
+                self.file_name = save.name();
+                self.info = None;
+                self.compare = None;
+
+                if let Some(info) = &files.info {
+                    self.upload_tasks.push(read(ctx, info, Msg::InfoLoaded));
+                }
+                if let Some(old) = &files.old {
+                    self.compare_task = Some(read_bytes(ctx, old, Msg::CompareLoaded));
+                    self.compare_file_name = old.name();
+                }
                 true
             }
             Msg::Loaded(data) => {
-                let save_game = stardew::load(&data);
-                self.upload_task = None;
-                self.content = Some(match save_game {
-                    Ok(sg) => format!("{:#?}", sg),
-                    Err(e) => format!("{:?}", e),
+                let files = match data.and_then(|data| upload::unpack(&self.file_name, data)) {
+                    Ok(files) => files,
+                    Err(e) => {
+                        self.warnings.clear();
+                        self.save_game = Some(Err(e));
+                        return true;
+                    }
+                };
+
+                if let Some(info) = files.info {
+                    ctx.link().send_message(Msg::InfoLoaded(Ok(info.content)));
+                }
+                if let Some(old) = files.old {
+                    self.compare = Some(load_compare(&old.content));
+                }
+
+                // Unpacking fails if there is no save, so it's always there.
+                let save = files.save.expect("unpacked files should contain a save");
+                let save_game = stardew_save::load_lenient(&save.content);
+                self.file_name = save.name;
+                self.farmer = 0;
+                self.download_error = None;
+                self.save_game = Some(match save_game {
+                    Ok((sg, warnings)) => {
+                        self.warnings = warnings.iter().map(ToString::to_string).collect();
+                        Ok(sg)
+                    }
+                    Err(e) => {
+                        self.warnings.clear();
+                        Err(e.to_string())
+                    }
                 });
+                self.source = save.content;
+                true
+            }
+            Msg::InfoLoaded(data) => {
+                self.info =
+                    Some(data.and_then(|data| {
+                        stardew_save::load_info(&data).map_err(|e| e.to_string())
+                    }));
+                true
+            }
+            Msg::SelectFarmer(index) => {
+                self.farmer = index;
+                true
+            }
+            Msg::CompareFiles(files) => match SaveFiles::new(files, File::name).save {
+                Some(file) => {
+                    self.compare_task = Some(read_bytes(ctx, &file, Msg::CompareLoaded));
+                    self.compare_file_name = file.name();
+                    true
+                }
+                None => false,
+            },
+            Msg::CompareLoaded(data) => {
+                self.compare_task = None;
+                self.compare = Some(
+                    data.and_then(|data| upload::unpack(&self.compare_file_name, data))
+                        .and_then(|files| {
+                            let save = files.save.expect("unpacked files should contain a save");
+                            load_compare(&save.content)
+                        }),
+                );
+                true
+            }
+            Msg::Edit(edit) => {
+                if let Some(Ok(sg)) = &mut self.save_game {
+                    if let Some(farmer) = sg.farmers_mut().nth(self.farmer) {
+                        edit.apply(farmer);
+                    }
+                }
+                true
+            }
+            Msg::Download => {
+                if let (Some(Ok(sg)), true) = (&self.save_game, self.warnings.is_empty()) {
+                    match stardew_save::write(sg, &self.source) {
+                        Ok(xml) => {
+                            self.download_error = None;
+                            self.download(xml);
+                        }
+                        Err(e) => self.download_error = Some(e.to_string()),
+                    }
+                }
                 true
             }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let on_change = ctx.link().callback(|event: Event| {
-            let target = event.target().expect("event should have target");
-            let files = target
-                .unchecked_into::<HtmlInputElement>()
-                .files()
-                .expect("target should have file list");
-            let file = FileList::from(files)
-                .first()
-                .expect("file list shouldn't be empty")
-                .clone();
-
-            Msg::File(file)
-        });
-
         html! {
             <section class="section">
                 <div class="container">
                     <h1 class="title">{"Figure out what's in your save game file"}</h1>
-                    <div class="block file">
-                        <label class="file-label">
-                            <input class="file-input" type="file" onchange={on_change} />
-                            <span class="file-cta">
-                                <span class="file-label">
-                                    { "Choose a file to scan" }
+                    <div class="block field is-grouped">
+                        <div class="control file">
+                            <label class="file-label">
+                                <input class="file-input" type="file" onchange={on_files_change(ctx, Msg::Files)} />
+                                <span class="file-cta">
+                                    <span class="file-label">
+                                        { "Choose a file to scan" }
+                                    </span>
                                 </span>
-                            </span>
-                        </label>
+                            </label>
+                        </div>
+                        <div class="control file">
+                            <label class="file-label">
+                                <input class="file-input" type="file" webkitdirectory="" onchange={on_files_change(ctx, Msg::Files)} />
+                                <span class="file-cta">
+                                    <span class="file-label">
+                                        { "Choose a save folder" }
+                                    </span>
+                                </span>
+                            </label>
+                        </div>
                     </div>
+                    { self.view_info() }
+                    if !self.warnings.is_empty() {
+                        <div class="block notification is-warning">
+                            <p>
+                                { format!("{} problems were skipped while loading this save:", self.warnings.len()) }
+                            </p>
+                            <ul>
+                                { for self.warnings.iter().map(|w| html! { <li><code>{ w }</code></li> }) }
+                            </ul>
+                        </div>
+                    }
+                    if let Some(Ok(sg)) = &self.save_game {
+                        { perfection::view(&stardew_save::perfection(sg)) }
+                        { bundles::view(&stardew_save::bundles(sg)) }
+                        { museum::view(&stardew_save::donations(sg)) }
+                    }
+                    { self.view_farmer_select(ctx) }
+                    { self.view_editor(ctx) }
+                    { self.view_monsters() }
+                    { self.view_compare(ctx) }
                     <div class="block content">
                     <pre>
                         {
-                            if let Some(content) = &self.content {
-                                content.as_ref()
-                            } else {
-                                "Content will be displayed here"
+                            match &self.save_game {
+                                Some(Ok(sg)) => sg
+                                    .farmers()
+                                    .nth(self.farmer)
+                                    .map(|farmer| {
+                                        serde_json::to_string_pretty(farmer)
+                                            .unwrap_or_else(|e| e.to_string())
+                                    })
+                                    .unwrap_or_default(),
+                                Some(Err(e)) => e.clone(),
+                                None => "Content will be displayed here".to_owned(),
                             }
                         }
                     </pre>
@@ -93,3 +243,197 @@ impl Component for App {
         }
     }
 }
+
+impl App {
+    fn view_info(&self) -> Html {
+        let info = match &self.info {
+            Some(Ok(info)) => info,
+            Some(Err(e)) => {
+                return html! {
+                    <div class="block notification is-warning">
+                        { format!("The SaveGameInfo file can't be read: {}", e) }
+                    </div>
+                }
+            }
+            None => return html! {},
+        };
+
+        html! {
+            <div class="block box">
+                <p class="title is-5">{ format!("{}, {} Farm", info.name, info.farm_name) }</p>
+                <p>
+                    { format!("{:?} {}, year {}", info.season, info.day_of_month, info.year) }
+                    { format!(" · {}g", info.money) }
+                    { format!(" · {}h played", info.milliseconds_played / 1000 / 60 / 60) }
+                    if let Some(version) = &info.game_version {
+                        { format!(" · version {}", version) }
+                    }
+                </p>
+            </div>
+        }
+    }
+
+    fn view_editor(&self, ctx: &Context<Self>) -> Html {
+        let farmer = match &self.save_game {
+            Some(Ok(sg)) => match sg.farmers().nth(self.farmer) {
+                Some(farmer) => farmer,
+                None => return html! {},
+            },
+            _ => return html! {},
+        };
+
+        html! {
+            <>
+                { editor::view(ctx.link(), farmer) }
+                if let Some(e) = &self.download_error {
+                    <div class="block notification is-danger">
+                        { format!("The save can't be written: {}", e) }
+                    </div>
+                }
+                <div class="block">
+                    <button class="button is-primary" disabled={!self.warnings.is_empty()} onclick={ctx.link().callback(|_| Msg::Download)}>
+                        { "Download modified save" }
+                    </button>
+                    if !self.warnings.is_empty() {
+                        <p class="help">{ "Saves with skipped problems can't be downloaded, as the skipped parts would be overwritten." }</p>
+                    }
+                </div>
+            </>
+        }
+    }
+
+    fn view_monsters(&self) -> Html {
+        match &self.save_game {
+            Some(Ok(sg)) => match sg.farmers().nth(self.farmer) {
+                Some(farmer) => monsters::view(&stardew_save::monster_goals(farmer)),
+                None => html! {},
+            },
+            _ => html! {},
+        }
+    }
+
+    fn view_compare(&self, ctx: &Context<Self>) -> Html {
+        let sg = match &self.save_game {
+            Some(Ok(sg)) => sg,
+            _ => return html! {},
+        };
+
+        html! {
+            <>
+                <h2 class="subtitle">{ "Changes" }</h2>
+                <div class="block file">
+                    <label class="file-label">
+                        <input class="file-input" type="file" onchange={on_files_change(ctx, Msg::CompareFiles)} />
+                        <span class="file-cta">
+                            <span class="file-label">
+                                { "Compare with an older save" }
+                            </span>
+                        </span>
+                    </label>
+                </div>
+                {
+                    match &self.compare {
+                        Some(Ok(old)) => diff::view(&stardew_save::diff(old, sg)),
+                        Some(Err(e)) => html! {
+                            <div class="block notification is-danger">{ e }</div>
+                        },
+                        None => html! {},
+                    }
+                }
+            </>
+        }
+    }
+
+    /// Offer the written save for download under its original file name, so it can replace the
+    /// file in the game's save folder directly.
+    fn download(&mut self, xml: String) {
+        let file = File::new(&self.file_name, xml.as_str());
+        let url = ObjectUrl::from(file);
+
+        let anchor = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("document should be available")
+            .create_element("a")
+            .expect("anchor element should be created")
+            .unchecked_into::<HtmlAnchorElement>();
+        anchor.set_href(&url);
+        anchor.set_download(&self.file_name);
+        anchor.click();
+
+        // Keep the URL alive until the next download, as it's revoked once dropped.
+        self.download = Some(url);
+    }
+
+    fn view_farmer_select(&self, ctx: &Context<Self>) -> Html {
+        let sg = match &self.save_game {
+            Some(Ok(sg)) if !sg.farmhands.is_empty() => sg,
+            _ => return html! {},
+        };
+
+        let on_change = ctx.link().callback(|event: Event| {
+            let target = event.target().expect("event should have target");
+            let index = target
+                .unchecked_into::<HtmlSelectElement>()
+                .selected_index();
+
+            Msg::SelectFarmer(index.try_into().unwrap_or_default())
+        });
+
+        html! {
+            <div class="block select">
+                <select onchange={on_change}>
+                    {
+                        for sg.farmers().enumerate().map(|(i, farmer)| html! {
+                            <option selected={i == self.farmer}>
+                                { if i == 0 { format!("{} (host)", farmer.name) } else { farmer.name.clone() } }
+                            </option>
+                        })
+                    }
+                </select>
+            </div>
+        }
+    }
+}
+
+fn on_files_change(ctx: &Context<App>, msg: fn(Vec<File>) -> Msg) -> Callback<Event> {
+    ctx.link().callback(move |event: Event| {
+        let target = event.target().expect("event should have target");
+        let files = target
+            .unchecked_into::<HtmlInputElement>()
+            .files()
+            .expect("target should have file list");
+
+        msg(FileList::from(files).to_vec())
+    })
+}
+
+fn read(ctx: &Context<App>, file: &File, msg: fn(Result<String, String>) -> Msg) -> FileReader {
+    let callback = ctx.link().callback(msg);
+    let name = file.name();
+    gloo_file::callbacks::read_as_text(file, move |res| {
+        callback.emit(res.map_err(|e| read_error(&name, e)))
+    })
+}
+
+fn read_bytes(
+    ctx: &Context<App>,
+    file: &File,
+    msg: fn(Result<Vec<u8>, String>) -> Msg,
+) -> FileReader {
+    let callback = ctx.link().callback(msg);
+    let name = file.name();
+    gloo_file::callbacks::read_as_bytes(file, move |res| {
+        callback.emit(res.map_err(|e| read_error(&name, e)))
+    })
+}
+
+fn read_error(name: &str, error: FileReadError) -> String {
+    format!("failed reading `{}`: {}", name, error)
+}
+
+/// The older save is only compared against, so problems while loading it aren't shown.
+fn load_compare(content: &str) -> Result<SaveGame, String> {
+    stardew_save::load_lenient(content)
+        .map(|(sg, _)| sg)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,67 @@
+use yew::prelude::*;
+
+use stardew_save::{Bundle, Bundles, RoomProgress, RoomState};
+
+/// Checklist of the items that are still needed for the Community Center.
+pub fn view(bundles: &Bundles) -> Html {
+    html! {
+        <div class="block">
+            <h2 class="subtitle">{ "Community Center" }</h2>
+            if bundles.joja_member {
+                <div class="block notification is-info">
+                    { "This farm has a Joja membership, so the remaining rooms can only be purchased at JojaMart." }
+                </div>
+            }
+            <div class="columns is-multiline">
+                { for bundles.rooms.iter().map(|room| view_room(room, bundles.joja_member)) }
+            </div>
+        </div>
+    }
+}
+
+fn view_room(room: &RoomProgress, joja_member: bool) -> Html {
+    let (tag, state) = match room.state {
+        RoomState::Incomplete => (
+            "is-light",
+            format!("{}/{}", room.completed_bundles(), room.bundles.len()),
+        ),
+        RoomState::Restored => ("is-success", "Restored".to_owned()),
+        RoomState::Purchased => ("is-info", "Purchased".to_owned()),
+    };
+
+    html! {
+        <div class="column is-one-third">
+            <div class="box">
+                <p class="title is-5">
+                    { room.room.to_string() }
+                    { " " }
+                    <span class={classes!("tag", tag)}>{ state }</span>
+                </p>
+                if room.state == RoomState::Incomplete && !joja_member {
+                    { for room.bundles.iter().filter(|bundle| !bundle.is_complete()).map(view_bundle) }
+                }
+            </div>
+        </div>
+    }
+}
+
+fn view_bundle(bundle: &Bundle) -> Html {
+    html! {
+        <div class="block">
+            <p class="has-text-weight-semibold">
+                { format!("{} ({} more)", bundle.name, bundle.remaining()) }
+            </p>
+            <ul>
+                {
+                    for bundle.items.iter().enumerate().map(|(i, item)| {
+                        if bundle.deposited.get(i).copied().unwrap_or_default() {
+                            html! { <li class="has-text-grey-light"><del>{ item.to_string() }</del></li> }
+                        } else {
+                            html! { <li>{ item.to_string() }</li> }
+                        }
+                    })
+                }
+            </ul>
+        </div>
+    }
+}
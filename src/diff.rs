@@ -0,0 +1,45 @@
+use yew::prelude::*;
+
+use stardew_save::{Change, ChangeKind};
+
+/// Side by side view of the changes between an older snapshot and the loaded save.
+pub fn view(changes: &[Change]) -> Html {
+    if changes.is_empty() {
+        return html! {
+            <div class="block notification">{ "Both saves are the same" }</div>
+        };
+    }
+
+    html! {
+        <table class="block table is-striped is-fullwidth">
+            <thead>
+                <tr>
+                    <th>{ "Farmer" }</th>
+                    <th>{ "Field" }</th>
+                    <th>{ "Before" }</th>
+                    <th>{ "After" }</th>
+                </tr>
+            </thead>
+            <tbody>
+                { for changes.iter().map(view_change) }
+            </tbody>
+        </table>
+    }
+}
+
+fn view_change(change: &Change) -> Html {
+    let (before, after) = match &change.kind {
+        ChangeKind::Changed { old, new } => (old.as_str(), new.as_str()),
+        ChangeKind::Added(entry) => ("", entry.as_str()),
+        ChangeKind::Removed(entry) => (entry.as_str(), ""),
+    };
+
+    html! {
+        <tr>
+            <td>{ &change.farmer }</td>
+            <td><code>{ &change.field }</code></td>
+            <td class="has-text-danger">{ before }</td>
+            <td class="has-text-success">{ after }</td>
+        </tr>
+    }
+}
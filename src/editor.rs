@@ -0,0 +1,219 @@
+use std::{fmt::Display, str::FromStr};
+
+use wasm_bindgen::JsCast;
+use web_sys::HtmlInputElement;
+use yew::{html::Scope, prelude::*};
+
+use stardew_save::Player;
+
+use crate::app::{App, Msg};
+
+#[derive(Clone, Copy)]
+pub enum Skill {
+    Farming,
+    Fishing,
+    Foraging,
+    Mining,
+    Combat,
+    Luck,
+}
+
+impl Skill {
+    const ALL: [Self; 6] = [
+        Self::Farming,
+        Self::Fishing,
+        Self::Foraging,
+        Self::Mining,
+        Self::Combat,
+        Self::Luck,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Farming => "Farming",
+            Self::Fishing => "Fishing",
+            Self::Foraging => "Foraging",
+            Self::Mining => "Mining",
+            Self::Combat => "Combat",
+            Self::Luck => "Luck",
+        }
+    }
+
+    /// Position of the skill in the player's experience points.
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn level(self, player: &Player) -> u8 {
+        match self {
+            Self::Farming => player.farming_level,
+            Self::Fishing => player.fishing_level,
+            Self::Foraging => player.foraging_level,
+            Self::Mining => player.mining_level,
+            Self::Combat => player.combat_level,
+            Self::Luck => player.luck_level,
+        }
+    }
+
+    fn level_mut(self, player: &mut Player) -> &mut u8 {
+        match self {
+            Self::Farming => &mut player.farming_level,
+            Self::Fishing => &mut player.fishing_level,
+            Self::Foraging => &mut player.foraging_level,
+            Self::Mining => &mut player.mining_level,
+            Self::Combat => &mut player.combat_level,
+            Self::Luck => &mut player.luck_level,
+        }
+    }
+}
+
+/// A single change to the selected farmer.
+pub enum Edit {
+    Money(u64),
+    Level(Skill, u8),
+    Experience(Skill, u64),
+    Stack(usize, u64),
+    Friendship(String, i32),
+}
+
+impl Edit {
+    pub fn apply(self, player: &mut Player) {
+        match self {
+            Self::Money(money) => player.money = money,
+            Self::Level(skill, level) => *skill.level_mut(player) = level,
+            Self::Experience(skill, xp) => {
+                if let Some(points) = player.experience_points.get_mut(skill.index()) {
+                    *points = xp;
+                }
+            }
+            Self::Stack(index, stack) => {
+                if let Some(item) = player.items.get_mut(index) {
+                    item.stack = stack;
+                }
+            }
+            Self::Friendship(name, points) => {
+                if let Some(friendship) = player.friendship_data.get_mut(&name) {
+                    friendship.points = points;
+                }
+            }
+        }
+    }
+}
+
+pub fn view(link: &Scope<App>, player: &Player) -> Html {
+    html! {
+        <div class="block">
+            <h2 class="subtitle">{ format!("Edit {}", player.name) }</h2>
+            <div class="columns is-multiline">
+                <div class="column is-half">
+                    { view_money(link, player) }
+                    { view_skills(link, player) }
+                </div>
+                <div class="column is-half">
+                    { view_items(link, player) }
+                    { view_friendships(link, player) }
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn view_money(link: &Scope<App>, player: &Player) -> Html {
+    html! {
+        <div class="field">
+            <label class="label">{ "Money" }</label>
+            <div class="control">
+                { number_input(link, player.money, Edit::Money) }
+            </div>
+        </div>
+    }
+}
+
+fn view_skills(link: &Scope<App>, player: &Player) -> Html {
+    html! {
+        <table class="table is-narrow">
+            <thead>
+                <tr><th>{ "Skill" }</th><th>{ "Level" }</th><th>{ "Experience" }</th></tr>
+            </thead>
+            <tbody>
+                {
+                    for Skill::ALL.iter().map(|&skill| html! {
+                        <tr>
+                            <td>{ skill.name() }</td>
+                            <td>{ number_input(link, skill.level(player), move |level| Edit::Level(skill, level)) }</td>
+                            <td>
+                                {
+                                    match player.experience_points.get(skill.index()) {
+                                        Some(&xp) => number_input(link, xp, move |xp| Edit::Experience(skill, xp)),
+                                        None => html! {},
+                                    }
+                                }
+                            </td>
+                        </tr>
+                    })
+                }
+            </tbody>
+        </table>
+    }
+}
+
+fn view_items(link: &Scope<App>, player: &Player) -> Html {
+    html! {
+        <table class="table is-narrow">
+            <thead>
+                <tr><th>{ "Item" }</th><th>{ "Stack" }</th></tr>
+            </thead>
+            <tbody>
+                {
+                    for player.items.iter().enumerate().map(|(index, item)| html! {
+                        <tr>
+                            <td>{ &item.display_name }</td>
+                            <td>{ number_input(link, item.stack, move |stack| Edit::Stack(index, stack)) }</td>
+                        </tr>
+                    })
+                }
+            </tbody>
+        </table>
+    }
+}
+
+fn view_friendships(link: &Scope<App>, player: &Player) -> Html {
+    html! {
+        <table class="table is-narrow">
+            <thead>
+                <tr><th>{ "Villager" }</th><th>{ "Friendship points" }</th></tr>
+            </thead>
+            <tbody>
+                {
+                    for player.friendship_data.iter().map(|(name, friendship)| {
+                        let name = name.clone();
+                        html! {
+                            <tr>
+                                <td>{ &name }</td>
+                                <td>{ number_input(link, friendship.points, move |points| Edit::Friendship(name.clone(), points)) }</td>
+                            </tr>
+                        }
+                    })
+                }
+            </tbody>
+        </table>
+    }
+}
+
+/// Number input that only reports a change if the new value is valid for the edited field.
+fn number_input<T, F>(link: &Scope<App>, value: T, edit: F) -> Html
+where
+    T: Display + FromStr + 'static,
+    F: Fn(T) -> Edit + 'static,
+{
+    let on_change = link.batch_callback(move |event: Event| {
+        let target = event.target().expect("event should have target");
+        let value = target.unchecked_into::<HtmlInputElement>().value();
+
+        value.parse().ok().map(|value| Msg::Edit(edit(value)))
+    });
+
+    html! {
+        <input class="input is-small" type="number" value={value.to_string()} onchange={on_change} />
+    }
+}
@@ -1,7 +1,13 @@
 #![recursion_limit = "512"]
 
 mod app;
-mod stardew;
+mod bundles;
+mod diff;
+mod editor;
+mod monsters;
+mod museum;
+mod perfection;
+mod upload;
 
 pub fn main() {
     console_error_panic_hook::set_once();
@@ -0,0 +1,55 @@
+use yew::prelude::*;
+
+use stardew_save::MonsterGoal;
+
+/// Adventurer's Guild eradication goals of the selected farmer.
+pub fn view(goals: &[MonsterGoal]) -> Html {
+    html! {
+        <div class="block">
+            <h2 class="subtitle">{ "Monster Eradication Goals" }</h2>
+            <table class="table is-striped is-fullwidth">
+                <thead>
+                    <tr>
+                        <th>{ "Target" }</th>
+                        <th>{ "Kills" }</th>
+                        <th>{ "Reward" }</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { for goals.iter().map(view_goal) }
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+fn view_goal(goal: &MonsterGoal) -> Html {
+    let monsters = goal
+        .monsters
+        .iter()
+        .map(|(monster, kills)| format!("{} {}", monster, kills))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    html! {
+        <tr>
+            <td>
+                <p>{ &goal.name }</p>
+                <p class="is-size-7 has-text-grey">{ monsters }</p>
+            </td>
+            <td>
+                { format!("{}/{}", goal.kills().min(goal.goal), goal.goal) }
+                <progress class="progress is-small is-danger" value={goal.kills().to_string()} max={goal.goal.to_string()} />
+            </td>
+            <td>
+                { &goal.reward }
+                { " " }
+                if goal.reward_claimed {
+                    <span class="tag is-success">{ "Claimed" }</span>
+                } else if goal.reward_available() {
+                    <span class="tag is-warning">{ "Ready at Gil" }</span>
+                }
+            </td>
+        </tr>
+    }
+}
@@ -0,0 +1,41 @@
+use yew::prelude::*;
+
+use stardew_save::{DonationState, Donations};
+
+/// Museum collection, grouped by whether each item still has to be found or only donated.
+pub fn view(donations: &Donations) -> Html {
+    let total = donations.artifacts.len() + donations.minerals.len();
+
+    html! {
+        <div class="block">
+            <h2 class="subtitle">{ format!("Museum {}/{}", donations.donated(), total) }</h2>
+            <div class="tags">
+                {
+                    for donations.milestones.iter().map(|milestone| html! {
+                        <span class={classes!("tag", milestone.reached.then_some("is-success"))}>
+                            { format!("{}: {}", milestone.donations, milestone.reward) }
+                        </span>
+                    })
+                }
+            </div>
+            <div class="columns">
+                { view_list(donations, DonationState::Found, "Found but not donated") }
+                { view_list(donations, DonationState::NeverFound, "Never found") }
+                { view_list(donations, DonationState::Donated, "Donated") }
+            </div>
+        </div>
+    }
+}
+
+fn view_list(donations: &Donations, state: DonationState, title: &str) -> Html {
+    html! {
+        <div class="column">
+            <p class="has-text-weight-semibold">
+                { format!("{} ({})", title, donations.with_state(state).count()) }
+            </p>
+            <ul>
+                { for donations.with_state(state).map(|item| html! { <li>{ &item.name }</li> }) }
+            </ul>
+        </div>
+    }
+}
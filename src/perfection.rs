@@ -0,0 +1,48 @@
+use yew::prelude::*;
+
+use stardew_save::{Perfection, Progress};
+
+/// Breakdown of the perfection score, with everything that's still missing for each goal.
+pub fn view(perfection: &Perfection) -> Html {
+    let percent = perfection.percent();
+
+    html! {
+        <div class="block">
+            <h2 class="subtitle">{ format!("Perfection {:.1}%", percent) }</h2>
+            <progress class="progress is-success" value={format!("{:.1}", percent)} max="100" />
+            <table class="table is-striped is-fullwidth">
+                <thead>
+                    <tr>
+                        <th>{ "Goal" }</th>
+                        <th>{ "Progress" }</th>
+                        <th>{ "Score" }</th>
+                        <th>{ "Missing" }</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { for perfection.goals.iter().map(view_progress) }
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+fn view_progress(progress: &Progress) -> Html {
+    html! {
+        <tr>
+            <td>{ progress.goal.to_string() }</td>
+            <td>{ format!("{}/{}", progress.done, progress.total) }</td>
+            <td>{ format!("{:.1}/{}", progress.score(), progress.goal.weight()) }</td>
+            <td>
+                if !progress.missing.is_empty() {
+                    <details>
+                        <summary>{ format!("{} left", progress.missing.len()) }</summary>
+                        <ul>
+                            { for progress.missing.iter().map(|missing| html! { <li>{ missing }</li> }) }
+                        </ul>
+                    </details>
+                }
+            </td>
+        </tr>
+    }
+}
@@ -0,0 +1,176 @@
+use std::io::{self, Cursor, Read};
+
+use flate2::read::GzDecoder;
+use zip::ZipArchive;
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+/// Largest file that is extracted from an archive. Even saves of big farms are far smaller, so
+/// anything above is a broken or malicious archive that would run out of memory.
+const MAX_FILE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Files of a save folder, which the game names `<farm>_<id>` after the save it contains.
+pub struct SaveFiles<T> {
+    pub save: Option<T>,
+    pub info: Option<T>,
+    pub old: Option<T>,
+}
+
+impl<T> SaveFiles<T> {
+    pub fn new(files: Vec<T>, name: impl Fn(&T) -> String) -> Self {
+        let mut save_files = Self {
+            save: None,
+            info: None,
+            old: None,
+        };
+
+        // A single file is always taken as the save itself, no matter its name.
+        if files.len() == 1 {
+            save_files.save = files.into_iter().next();
+            return save_files;
+        }
+
+        for file in files {
+            let slot = match name(&file).as_str() {
+                "SaveGameInfo" => &mut save_files.info,
+                "SaveGameInfo_old" => continue,
+                name if name.ends_with("_old") => &mut save_files.old,
+                name if name.contains('_') && !name.contains('.') => &mut save_files.save,
+                _ => continue,
+            };
+            slot.get_or_insert(file);
+        }
+
+        save_files
+    }
+
+    fn single(save: T) -> Self {
+        Self {
+            save: Some(save),
+            info: None,
+            old: None,
+        }
+    }
+}
+
+/// A file with its name and text content.
+pub struct Entry {
+    pub name: String,
+    pub content: String,
+}
+
+/// Extract the save files from an upload, which is either the save file itself or a zip or
+/// gzip archive of it. The container is detected from its content, as shared files often don't
+/// have the right extension.
+pub fn unpack(name: &str, data: Vec<u8>) -> Result<SaveFiles<Entry>, String> {
+    if data.starts_with(ZIP_MAGIC) {
+        unzip(data)
+    } else if data.starts_with(GZIP_MAGIC) {
+        gunzip(name, &data)
+    } else {
+        text(name.to_owned(), data).map(SaveFiles::single)
+    }
+}
+
+fn unzip(data: Vec<u8>) -> Result<SaveFiles<Entry>, String> {
+    let mut archive =
+        ZipArchive::new(Cursor::new(data)).map_err(|e| format!("invalid zip archive: {}", e))?;
+    let mut entries = Vec::with_capacity(archive.len());
+
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| format!("invalid zip archive: {}", e))?;
+        if file.is_dir() {
+            continue;
+        }
+
+        // Only the file name is relevant, not the folder it's in within the archive.
+        let name = file
+            .name()
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+        let size = file.size();
+        if size > MAX_FILE_SIZE {
+            return Err(format!("failed extracting `{}`: {}", name, too_large()));
+        }
+        let data = read_limited(&mut file, size)
+            .map_err(|e| format!("failed extracting `{}`: {}", name, e))?;
+
+        entries.push((name, data));
+    }
+
+    let files = SaveFiles::new(entries, |(name, _)| name.clone());
+    if files.save.is_none() {
+        return Err("the zip archive doesn't contain a save file".to_owned());
+    }
+
+    Ok(SaveFiles {
+        save: files
+            .save
+            .map(|(name, data)| text(name, data))
+            .transpose()?,
+        info: files
+            .info
+            .map(|(name, data)| text(name, data))
+            .transpose()?,
+        old: files.old.map(|(name, data)| text(name, data)).transpose()?,
+    })
+}
+
+fn gunzip(name: &str, data: &[u8]) -> Result<SaveFiles<Entry>, String> {
+    let mut decoder = GzDecoder::new(data);
+    let content = read_limited(&mut decoder, data.len() as u64)
+        .map_err(|e| format!("invalid gzip archive: {}", e))?;
+
+    // The original file name is usually kept in the header, otherwise the `.gz` extension is
+    // the best guess.
+    let name = decoder
+        .header()
+        .and_then(|header| header.filename())
+        .map(|filename| String::from_utf8_lossy(filename).into_owned())
+        .unwrap_or_else(|| name.trim_end_matches(".gz").to_owned());
+
+    text(name, content).map(SaveFiles::single)
+}
+
+/// Read a file from an archive, but no more than [`MAX_FILE_SIZE`], as the sizes in archive
+/// headers can't be trusted.
+fn read_limited(reader: impl Read, size_hint: u64) -> io::Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(size_hint.min(MAX_FILE_SIZE) as usize);
+    reader.take(MAX_FILE_SIZE + 1).read_to_end(&mut data)?;
+
+    if data.len() as u64 > MAX_FILE_SIZE {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, too_large()));
+    }
+
+    Ok(data)
+}
+
+fn too_large() -> String {
+    format!(
+        "larger than {} MiB, which no save file is",
+        MAX_FILE_SIZE / 1024 / 1024
+    )
+}
+
+fn text(name: String, data: Vec<u8>) -> Result<Entry, String> {
+    let content = String::from_utf8(data)
+        .ok()
+        .filter(|content| {
+            content
+                .trim_start_matches('\u{feff}')
+                .trim_start()
+                .starts_with('<')
+        })
+        .ok_or_else(|| {
+            format!(
+                "`{}` is neither a save file nor a zip or gzip archive",
+                name
+            )
+        })?;
+
+    Ok(Entry { name, content })
+}
@@ -0,0 +1,329 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use stardew_save::{DonationState, Player, RoomState, SaveGame};
+
+/// Inspect Stardew Valley save games from the command line.
+#[derive(Parser)]
+#[command(about, version)]
+struct Cli {
+    /// Save game file, or the save folder that contains it.
+    path: PathBuf,
+    /// Name of the farmer to show, defaults to the host.
+    #[arg(short, long, global = true)]
+    farmer: Option<String>,
+    /// Skip over missing or malformed elements instead of failing, and report them as warnings.
+    #[arg(short, long, global = true)]
+    lenient: bool,
+    #[command(subcommand)]
+    cmd: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Overview of the farm and its farmers.
+    Summary,
+    /// Items in the farmer's inventory.
+    Inventory,
+    /// Friendship levels with all villagers.
+    Friends,
+    /// Skill levels and experience.
+    Skills,
+    /// Community Center bundles and the items they still need.
+    Bundles,
+    /// Adventurer's Guild monster eradication goals and their rewards.
+    Monsters,
+    /// Museum donations and the artifacts and minerals that are still missing.
+    Museum,
+    /// Perfection score and what's still missing for it.
+    Perfection,
+    /// Changes since an older snapshot of the same save, like a backup or the `_old` file.
+    Diff {
+        /// Save game file or folder to compare against.
+        old: PathBuf,
+    },
+    /// Print the whole parsed save game.
+    Dump {
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Rust debug representation.
+    Debug,
+    Json,
+    Yaml,
+    /// Binary MessagePack, best redirected into a file.
+    #[value(name = "msgpack")]
+    MessagePack,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let save_game = load(&cli.path, cli.lenient)?;
+
+    match cli.cmd {
+        Command::Summary => summary(&save_game),
+        Command::Inventory => inventory(select_farmer(&save_game, cli.farmer.as_deref())?),
+        Command::Friends => friends(select_farmer(&save_game, cli.farmer.as_deref())?),
+        Command::Skills => skills(select_farmer(&save_game, cli.farmer.as_deref())?),
+        Command::Bundles => bundles(&save_game),
+        Command::Monsters => monsters(select_farmer(&save_game, cli.farmer.as_deref())?),
+        Command::Museum => museum(&save_game),
+        Command::Perfection => perfection(&save_game),
+        Command::Diff { old } => diff(&load(&old, cli.lenient)?, &save_game),
+        Command::Dump { format } => dump(&save_game, format)?,
+    }
+
+    Ok(())
+}
+
+/// Save folders are named `<farm>_<id>` and contain the main save file under the same name.
+fn resolve_path(path: &Path) -> Result<PathBuf> {
+    if !path.is_dir() {
+        return Ok(path.to_owned());
+    }
+
+    let name = path
+        .file_name()
+        .with_context(|| format!("invalid save folder `{}`", path.display()))?;
+
+    Ok(path.join(name))
+}
+
+fn load(path: &Path, lenient: bool) -> Result<SaveGame> {
+    let path = resolve_path(path)?;
+    let data = fs::read_to_string(&path)
+        .with_context(|| format!("failed reading save file `{}`", path.display()))?;
+
+    if !lenient {
+        return stardew_save::load(&data)
+            .with_context(|| format!("failed parsing save file `{}`", path.display()));
+    }
+
+    let (save_game, warnings) = stardew_save::load_lenient(&data)
+        .with_context(|| format!("failed parsing save file `{}`", path.display()))?;
+
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    Ok(save_game)
+}
+
+fn select_farmer<'a>(save_game: &'a SaveGame, name: Option<&str>) -> Result<&'a Player> {
+    let name = match name {
+        Some(name) => name,
+        None => return Ok(&save_game.player),
+    };
+
+    match save_game.farmers().find(|farmer| farmer.name == name) {
+        Some(farmer) => Ok(farmer),
+        None => bail!("no farmer named `{}` in this save", name),
+    }
+}
+
+fn bundles(save_game: &SaveGame) {
+    let bundles = stardew_save::bundles(save_game);
+    if bundles.joja_member {
+        println!("Joja member, rooms can only be finished by purchase");
+    }
+
+    for room in &bundles.rooms {
+        let state = match room.state {
+            RoomState::Incomplete => format!(
+                "{}/{} bundles",
+                room.completed_bundles(),
+                room.bundles.len()
+            ),
+            RoomState::Restored => "restored".to_owned(),
+            RoomState::Purchased => "purchased from Joja".to_owned(),
+        };
+        println!("{} ({})", room.room, state);
+
+        if bundles.joja_member || room.state != RoomState::Incomplete {
+            continue;
+        }
+        for bundle in room.bundles.iter().filter(|bundle| !bundle.is_complete()) {
+            println!("  {}, {} more of:", bundle.name, bundle.remaining());
+            for item in bundle.missing() {
+                println!("    {}", item);
+            }
+        }
+    }
+}
+
+fn monsters(player: &Player) {
+    for goal in stardew_save::monster_goals(player) {
+        let reward = if goal.reward_claimed {
+            "claimed"
+        } else if goal.reward_available() {
+            "ready at Gil"
+        } else {
+            "locked"
+        };
+
+        println!(
+            "{:<14} {:>4}/{:<4} {} ({})",
+            goal.name,
+            goal.kills(),
+            goal.goal,
+            goal.reward,
+            reward
+        );
+    }
+}
+
+fn museum(save_game: &SaveGame) {
+    let donations = stardew_save::donations(save_game);
+    println!("Donated: {}", donations.donated());
+
+    for milestone in &donations.milestones {
+        println!(
+            "  [{}] {:>2} donations: {}",
+            if milestone.reached { "x" } else { " " },
+            milestone.donations,
+            milestone.reward
+        );
+    }
+
+    for (state, title) in [
+        (DonationState::Found, "Found but not donated"),
+        (DonationState::NeverFound, "Never found"),
+    ] {
+        let names = donations
+            .with_state(state)
+            .map(|item| item.name.as_str())
+            .collect::<Vec<_>>();
+        if !names.is_empty() {
+            println!("{}: {}", title, names.join(", "));
+        }
+    }
+}
+
+fn perfection(save_game: &SaveGame) {
+    let perfection = stardew_save::perfection(save_game);
+    println!("Perfection: {:.1}%", perfection.percent());
+
+    for progress in &perfection.goals {
+        println!(
+            "  {:<26} {:>3}/{:<3} {:>5.1}%",
+            progress.goal.to_string(),
+            progress.done,
+            progress.total,
+            progress.score()
+        );
+        if !progress.missing.is_empty() {
+            println!("    missing: {}", progress.missing.join(", "));
+        }
+    }
+}
+
+fn diff(old: &SaveGame, new: &SaveGame) {
+    let changes = stardew_save::diff(old, new);
+    if changes.is_empty() {
+        println!("No changes");
+    }
+
+    for change in changes {
+        println!("{}", change);
+    }
+}
+
+fn dump(save_game: &SaveGame, format: Format) -> Result<()> {
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    match format {
+        Format::Debug => writeln!(stdout, "{:#?}", save_game)?,
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut stdout, save_game)?;
+            writeln!(stdout)?;
+        }
+        Format::Yaml => serde_yaml::to_writer(&mut stdout, save_game)?,
+        Format::MessagePack => rmp_serde::encode::write_named(&mut stdout, save_game)?,
+    }
+
+    stdout.flush()?;
+    Ok(())
+}
+
+fn summary(save_game: &SaveGame) {
+    let player = &save_game.player;
+
+    println!("Farm:     {} Farm", player.farm_name);
+    println!(
+        "Date:     {} {}, year {}",
+        save_game.current_season, save_game.day_of_month, save_game.year
+    );
+    println!("Version:  {}", save_game.game_version);
+    println!();
+
+    for farmer in save_game.farmers() {
+        println!("{}", farmer.name);
+        println!("  Money:        {}g", farmer.money);
+        println!("  Total earned: {}g", farmer.total_money_earned);
+        println!(
+            "  Played:       {}h",
+            farmer.milliseconds_played / 1000 / 60 / 60
+        );
+        println!("  Mine level:   {}", farmer.deepest_mine_level);
+    }
+}
+
+fn inventory(player: &Player) {
+    for item in &player.items {
+        match item.data() {
+            Some(data) => println!(
+                "{:>4} x {} ({}, {}g)",
+                item.stack, item.display_name, data.category, data.price
+            ),
+            None => println!("{:>4} x {}", item.stack, item.display_name),
+        }
+    }
+}
+
+fn friends(player: &Player) {
+    let name_width = player
+        .friendship_data
+        .keys()
+        .map(String::len)
+        .max()
+        .unwrap_or_default();
+
+    for (name, friendship) in &player.friendship_data {
+        println!(
+            "{:width$}  {:<10} {:>4} points  {:2} hearts  {:?}",
+            name,
+            "\u{2665}".repeat(friendship.hearts().into()),
+            friendship.points,
+            friendship.hearts(),
+            friendship.status,
+            width = name_width,
+        );
+    }
+}
+
+fn skills(player: &Player) {
+    // Experience points are stored in the game's internal skill order.
+    let skills = [
+        ("Farming", player.farming_level),
+        ("Fishing", player.fishing_level),
+        ("Foraging", player.foraging_level),
+        ("Mining", player.mining_level),
+        ("Combat", player.combat_level),
+        ("Luck", player.luck_level),
+    ];
+
+    for (i, (name, level)) in skills.iter().enumerate() {
+        let experience = player.experience_points.get(i).copied().unwrap_or_default();
+        println!("{:<8}  level {:>2}  {:>6} xp", name, level, experience);
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    data::GameData,
+    error::UnknownVariant,
+    item::{ItemId, Quality},
+    location::CommunityCenter,
+    save_game::SaveGame,
+};
+
+/// Mail flag of farmers that bought a Joja membership, which closes the Community Center.
+const JOJA_MEMBER: &str = "JojaMember";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Room {
+    Pantry,
+    CraftsRoom,
+    FishTank,
+    BoilerRoom,
+    Vault,
+    BulletinBoard,
+    AbandonedJojaMart,
+}
+
+impl Room {
+    /// Position of the room in the Community Center's completed areas.
+    fn area(self) -> usize {
+        self as usize
+    }
+
+    /// Mail flag the host receives once the Junimos restored the room.
+    fn restored_flag(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Pantry => "ccPantry",
+            Self::CraftsRoom => "ccCraftsRoom",
+            Self::FishTank => "ccFishTank",
+            Self::BoilerRoom => "ccBoilerRoom",
+            Self::Vault => "ccVault",
+            Self::BulletinBoard => "ccBulletin",
+            Self::AbandonedJojaMart => "ccMovieTheater",
+        })
+    }
+
+    /// Mail flag the host receives once the matching Joja development was bought instead. The
+    /// Abandoned Joja Mart becomes the movie theater either way, so its flag is for the theater.
+    fn purchased_flag(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Pantry => "jojaPantry",
+            Self::CraftsRoom => "jojaCraftsRoom",
+            Self::FishTank => "jojaFishTank",
+            Self::BoilerRoom => "jojaBoilerRoom",
+            Self::Vault => "jojaVault",
+            Self::AbandonedJojaMart => "ccMovieTheaterJoja",
+            Self::BulletinBoard => return None,
+        })
+    }
+}
+
+impl FromStr for Room {
+    type Err = UnknownVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Pantry" => Self::Pantry,
+            "Crafts Room" => Self::CraftsRoom,
+            "Fish Tank" => Self::FishTank,
+            "Boiler Room" => Self::BoilerRoom,
+            "Vault" => Self::Vault,
+            "Bulletin Board" => Self::BulletinBoard,
+            "Abandoned Joja Mart" => Self::AbandonedJojaMart,
+            _ => return Err(UnknownVariant::new("room", s)),
+        })
+    }
+}
+
+impl Display for Room {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pantry => "Pantry",
+            Self::CraftsRoom => "Crafts Room",
+            Self::FishTank => "Fish Tank",
+            Self::BoilerRoom => "Boiler Room",
+            Self::Vault => "Vault",
+            Self::BulletinBoard => "Bulletin Board",
+            Self::AbandonedJojaMart => "Abandoned Joja Mart",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoomState {
+    Incomplete,
+    Restored,
+    /// Finished by buying the matching development from Joja.
+    Purchased,
+}
+
+/// Progress of all Community Center bundles.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bundles {
+    pub joja_member: bool,
+    pub rooms: Vec<RoomProgress>,
+}
+
+impl Bundles {
+    /// Items that still have to be delivered, skipping rooms that are already done. Joja members
+    /// can't deliver anything anymore, so nothing is needed for them.
+    pub fn still_needed(&self) -> impl Iterator<Item = (&RoomProgress, &Bundle, &BundleItem)> {
+        self.rooms
+            .iter()
+            .filter(move |room| !self.joja_member && room.state == RoomState::Incomplete)
+            .flat_map(|room| {
+                room.bundles
+                    .iter()
+                    .filter(|bundle| !bundle.is_complete())
+                    .flat_map(move |bundle| bundle.missing().map(move |item| (room, bundle, item)))
+            })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoomProgress {
+    pub room: Room,
+    pub state: RoomState,
+    pub bundles: Vec<Bundle>,
+}
+
+impl RoomProgress {
+    pub fn completed_bundles(&self) -> usize {
+        self.bundles
+            .iter()
+            .filter(|bundle| bundle.is_complete())
+            .count()
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bundle {
+    pub id: u64,
+    pub name: String,
+    pub reward: Option<BundleItem>,
+    pub items: Vec<BundleItem>,
+    /// Number of items needed to complete the bundle, which can be less than the items to
+    /// choose from.
+    pub required: usize,
+    /// Whether each of the items was already put into the bundle.
+    pub deposited: Vec<bool>,
+    pub reward_collected: bool,
+}
+
+impl Bundle {
+    /// Parse an entry of the bundle data, which is `<room>/<id>` and
+    /// `<name>/<reward>/<items>/<color>[/<required>[/<sprite>[/<display name>]]]`.
+    fn parse(key: &str, value: &str) -> Option<(Room, Self)> {
+        let (room, id) = key.split_once('/')?;
+        let fields = value.split('/').collect::<Vec<_>>();
+
+        let items = fields
+            .get(2)?
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .chunks(3)
+            .map(BundleItem::parse)
+            .collect::<Option<Vec<_>>>()?;
+        let required = fields
+            .get(4)
+            .and_then(|required| required.parse().ok())
+            .unwrap_or(items.len());
+        let name = fields
+            .get(6)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&fields[0]);
+
+        Some((
+            room.parse().ok()?,
+            Self {
+                id: id.parse().ok()?,
+                name: (*name).to_owned(),
+                reward: BundleItem::parse_reward(fields.get(1)?),
+                items,
+                required,
+                deposited: Vec::new(),
+                reward_collected: false,
+            },
+        ))
+    }
+
+    pub fn deposited_count(&self) -> usize {
+        self.deposited
+            .iter()
+            .filter(|&&deposited| deposited)
+            .count()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.deposited_count() >= self.required
+    }
+
+    /// Items that weren't put into the bundle yet. Only [`Self::remaining`] of them are still
+    /// needed if the bundle lets the farmer choose.
+    pub fn missing(&self) -> impl Iterator<Item = &BundleItem> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| !self.deposited.get(*i).copied().unwrap_or_default())
+            .map(|(_, item)| item)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.required.saturating_sub(self.deposited_count())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BundleItem {
+    /// The item, or `-1` for money in the vault bundles.
+    pub item: ItemId,
+    pub stack: u64,
+    pub quality: Quality,
+}
+
+impl BundleItem {
+    /// Items are `<id> <stack> <quality>`, where bundles count iridium quality as `3`.
+    fn parse(fields: &[&str]) -> Option<Self> {
+        match *fields {
+            [id, stack, quality] => Some(Self {
+                item: id.parse().ok()?,
+                stack: stack.parse().ok()?,
+                quality: match quality {
+                    "1" => Quality::Silver,
+                    "2" => Quality::Gold,
+                    "3" | "4" => Quality::Iridium,
+                    _ => Quality::Normal,
+                },
+            }),
+            _ => None,
+        }
+    }
+
+    /// Rewards are `<type> <id> <stack>`, with the type being a shorter form of the item ID's
+    /// qualifier.
+    fn parse_reward(reward: &str) -> Option<Self> {
+        let mut fields = reward.split_whitespace();
+        let qualifier = match fields.next()? {
+            "O" | "R" => "O",
+            "BO" => "BC",
+            ty => ty,
+        };
+
+        Some(Self {
+            item: ItemId {
+                qualifier: Some(qualifier.to_owned()),
+                id: fields.next()?.to_owned(),
+            },
+            stack: fields.next()?.parse().ok()?,
+            quality: Quality::Normal,
+        })
+    }
+
+    pub fn is_money(&self) -> bool {
+        self.item.id == "-1"
+    }
+
+    /// Display name of the item, falling back to its ID if it's not part of the game data.
+    pub fn name(&self) -> String {
+        GameData::get()
+            .item(&self.item)
+            .map_or_else(|| self.item.to_string(), |data| data.name.to_owned())
+    }
+}
+
+impl Display for BundleItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_money() {
+            return write!(f, "{}g", self.stack);
+        }
+
+        f.write_str(&self.name())?;
+        if self.stack > 1 {
+            write!(f, " x{}", self.stack)?;
+        }
+        match self.quality {
+            Quality::Normal => Ok(()),
+            Quality::Silver => f.write_str(" (silver)"),
+            Quality::Gold => f.write_str(" (gold)"),
+            Quality::Iridium => f.write_str(" (iridium)"),
+        }
+    }
+}
+
+/// Collect the progress of all Community Center bundles, using the save's own bundle data if it
+/// has any, as bundles can be remixed since 1.5.
+pub fn bundles(save_game: &SaveGame) -> Bundles {
+    let default = CommunityCenter::default();
+    let cc = save_game
+        .locations
+        .iter()
+        .find_map(|location| location.community_center.as_ref())
+        .unwrap_or(&default);
+    let mail = &save_game.player.mail_received;
+    let has_mail = |flag: &str| mail.iter().any(|m| m == flag);
+
+    let mut bundles = if save_game.bundle_data.is_empty() {
+        GameData::get()
+            .bundles()
+            .filter_map(|(key, value)| Bundle::parse(key, value))
+            .collect::<Vec<_>>()
+    } else {
+        save_game
+            .bundle_data
+            .iter()
+            .filter_map(|(key, value)| Bundle::parse(key, value))
+            .collect()
+    };
+    bundles.sort_by_key(|(room, bundle)| (*room, bundle.id));
+
+    let state = |room: Room| {
+        let restored = cc.areas_complete.get(room.area()).copied();
+        if restored.unwrap_or_default() || room.restored_flag().is_some_and(has_mail) {
+            RoomState::Restored
+        } else if room.purchased_flag().is_some_and(has_mail) {
+            RoomState::Purchased
+        } else {
+            RoomState::Incomplete
+        }
+    };
+
+    let mut rooms = Vec::<RoomProgress>::new();
+    for (room, mut bundle) in bundles {
+        bundle.deposited = cc.bundles.get(&bundle.id).cloned().unwrap_or_default();
+        bundle.reward_collected = cc
+            .bundle_rewards
+            .get(&bundle.id)
+            .copied()
+            .unwrap_or_default();
+
+        match rooms.last_mut() {
+            Some(progress) if progress.room == room => progress.bundles.push(bundle),
+            _ => rooms.push(RoomProgress {
+                room,
+                state: state(room),
+                bundles: vec![bundle],
+            }),
+        }
+    }
+
+    Bundles {
+        joja_member: has_mail(JOJA_MEMBER),
+        rooms,
+    }
+}
@@ -0,0 +1,340 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    sync::OnceLock,
+};
+
+use crate::{item::ItemId, save_game::Season};
+
+const OBJECTS: &str = include_str!("../data/objects.txt");
+const BIG_CRAFTABLES: &str = include_str!("../data/big_craftables.txt");
+const NPCS: &str = include_str!("../data/npcs.txt");
+const ACHIEVEMENTS: &str = include_str!("../data/achievements.txt");
+const EVENTS: &str = include_str!("../data/events.txt");
+const LOCATIONS: &str = include_str!("../data/locations.txt");
+const BUNDLES: &str = include_str!("../data/bundles.txt");
+const CRAFTING_RECIPES: &str = include_str!("../data/crafting_recipes.txt");
+
+/// Lookup tables of the vanilla game, to resolve the plain IDs and names stored in a save into
+/// something readable.
+///
+/// The tables are a subset of the game's own data. Objects and big craftables cover what the
+/// collections and bundles track, events only cover the main story, and other item types like
+/// hats, furniture, weapons, boots, clothing, flooring and wallpaper aren't bundled at all.
+/// Anything that isn't bundled is simply not found.
+pub struct GameData {
+    objects: BTreeMap<u64, ItemData>,
+    big_craftables: BTreeMap<u64, ItemData>,
+    npcs: BTreeMap<&'static str, NpcData>,
+    achievements: BTreeMap<u64, Achievement>,
+    events: BTreeMap<u64, &'static str>,
+    locations: BTreeMap<&'static str, &'static str>,
+    crafting_recipes: Vec<&'static str>,
+}
+
+impl GameData {
+    /// The bundled tables, which are parsed on first use.
+    pub fn get() -> &'static Self {
+        static DATA: OnceLock<GameData> = OnceLock::new();
+        DATA.get_or_init(Self::parse)
+    }
+
+    fn parse() -> Self {
+        Self {
+            objects: parse_items(OBJECTS),
+            big_craftables: parse_items(BIG_CRAFTABLES),
+            npcs: entries(NPCS)
+                .map(|(name, value)| (name, NpcData::parse(name, value)))
+                .collect(),
+            achievements: entries(ACHIEVEMENTS)
+                .map(|(id, value)| {
+                    let id = parse_id(id);
+                    (id, Achievement::parse(id, value))
+                })
+                .collect(),
+            events: entries(EVENTS)
+                .map(|(id, value)| (parse_id(id), value))
+                .collect(),
+            locations: entries(LOCATIONS).collect(),
+            crafting_recipes: lines(CRAFTING_RECIPES).collect(),
+        }
+    }
+
+    /// Look up an object or big craftable, based on the type qualifier of its ID. IDs of any
+    /// other item type aren't resolved.
+    pub fn item(&self, id: &ItemId) -> Option<&ItemData> {
+        let index = id.index()?;
+
+        match id.qualifier.as_deref() {
+            None | Some("O") => self.object(index),
+            Some("BC") => self.big_craftable(index),
+            Some(_) => None,
+        }
+    }
+
+    pub fn object(&self, index: u64) -> Option<&ItemData> {
+        self.objects.get(&index)
+    }
+
+    pub fn objects(&self) -> impl Iterator<Item = &ItemData> {
+        self.objects.values()
+    }
+
+    pub fn big_craftable(&self, index: u64) -> Option<&ItemData> {
+        self.big_craftables.get(&index)
+    }
+
+    pub fn npc(&self, name: &str) -> Option<&NpcData> {
+        self.npcs.get(name)
+    }
+
+    pub fn npcs(&self) -> impl Iterator<Item = &NpcData> {
+        self.npcs.values()
+    }
+
+    pub fn achievement(&self, id: u64) -> Option<&Achievement> {
+        self.achievements.get(&id)
+    }
+
+    /// Short description of a story event. Heart events and festivals aren't bundled.
+    pub fn event(&self, id: u64) -> Option<&'static str> {
+        self.events.get(&id).copied()
+    }
+
+    /// Display name of a location.
+    pub fn location(&self, name: &str) -> Option<&'static str> {
+        self.locations.get(name).copied()
+    }
+
+    /// Names of all crafting recipes.
+    pub fn crafting_recipes(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.crafting_recipes.iter().copied()
+    }
+
+    /// Community Center bundles of the unmodified game, in the same format as the save's own
+    /// bundle data.
+    pub(crate) fn bundles(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        entries(BUNDLES)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItemData {
+    pub index: u64,
+    pub name: &'static str,
+    /// Base price when selling the item, before any quality or profession bonus.
+    pub price: u32,
+    pub category: Category,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    Gem,
+    Fish,
+    Egg,
+    Milk,
+    Cooking,
+    Crafting,
+    BigCraftable,
+    Mineral,
+    Meat,
+    MetalResource,
+    BuildingResource,
+    SellAtPierresAndMarnies,
+    AnimalProduct,
+    Fertilizer,
+    Junk,
+    Bait,
+    Tackle,
+    SellAtFishShop,
+    Ingredient,
+    ArtisanGoods,
+    Syrup,
+    MonsterLoot,
+    Seed,
+    Vegetable,
+    Fruit,
+    Flower,
+    Forage,
+    Artifact,
+    Other(i32),
+}
+
+impl Category {
+    /// Categories are negative numbers, except for artifacts and some special items that don't
+    /// have a category and are only told apart by their type.
+    fn new(ty: &str, id: i32) -> Self {
+        match id {
+            -2 => Self::Gem,
+            -4 => Self::Fish,
+            -5 => Self::Egg,
+            -6 => Self::Milk,
+            -7 => Self::Cooking,
+            -8 => Self::Crafting,
+            -9 => Self::BigCraftable,
+            -12 => Self::Mineral,
+            -14 => Self::Meat,
+            -15 => Self::MetalResource,
+            -16 => Self::BuildingResource,
+            -17 => Self::SellAtPierresAndMarnies,
+            -18 => Self::AnimalProduct,
+            -19 => Self::Fertilizer,
+            -20 => Self::Junk,
+            -21 => Self::Bait,
+            -22 => Self::Tackle,
+            -23 => Self::SellAtFishShop,
+            -25 => Self::Ingredient,
+            -26 => Self::ArtisanGoods,
+            -27 => Self::Syrup,
+            -28 => Self::MonsterLoot,
+            -74 => Self::Seed,
+            -75 => Self::Vegetable,
+            -79 => Self::Fruit,
+            -80 => Self::Flower,
+            -81 => Self::Forage,
+            0 if ty == "Arch" => Self::Artifact,
+            id => Self::Other(id),
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Gem => "Gem",
+            Self::Fish => "Fish",
+            Self::Egg => "Egg",
+            Self::Milk => "Milk",
+            Self::Cooking => "Cooking",
+            Self::Crafting | Self::BigCraftable => "Crafting",
+            Self::Mineral => "Mineral",
+            Self::Meat => "Meat",
+            Self::MetalResource | Self::BuildingResource => "Resource",
+            Self::SellAtPierresAndMarnies | Self::AnimalProduct => "Animal Product",
+            Self::Fertilizer => "Fertilizer",
+            Self::Junk => "Trash",
+            Self::Bait => "Bait",
+            Self::Tackle => "Fishing Tackle",
+            Self::SellAtFishShop => "Fish Shop",
+            Self::Ingredient => "Ingredient",
+            Self::ArtisanGoods => "Artisan Goods",
+            Self::Syrup => "Tapper Product",
+            Self::MonsterLoot => "Monster Loot",
+            Self::Seed => "Seed",
+            Self::Vegetable => "Vegetable",
+            Self::Fruit => "Fruit",
+            Self::Flower => "Flower",
+            Self::Forage => "Forage",
+            Self::Artifact => "Artifact",
+            Self::Other(_) => "Other",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NpcData {
+    pub name: &'static str,
+    pub display_name: &'static str,
+    pub datable: bool,
+    pub birthday: (Season, u8),
+    /// Location the villager lives in.
+    pub home: &'static str,
+}
+
+impl NpcData {
+    fn parse(name: &'static str, value: &'static str) -> Self {
+        let mut fields = value.split('/');
+        let mut field = || fields.next().expect("bundled NPC data should be complete");
+
+        let datable = field() == "datable";
+        let (season, day) = field()
+            .split_once(' ')
+            .expect("bundled NPC birthday should have season and day");
+
+        Self {
+            name,
+            datable,
+            birthday: (
+                season
+                    .parse()
+                    .expect("bundled NPC birthday should be valid"),
+                parse_id(day) as u8,
+            ),
+            home: field(),
+            display_name: field(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Achievement {
+    pub id: u64,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+impl Achievement {
+    fn parse(id: u64, value: &'static str) -> Self {
+        let (name, description) = value
+            .split_once('/')
+            .expect("bundled achievement should have a description");
+
+        Self {
+            id,
+            name,
+            description,
+        }
+    }
+}
+
+/// Lines of a bundled table, skipping empty lines and comments.
+fn lines(table: &'static str) -> impl Iterator<Item = &'static str> {
+    table
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Lines of a bundled table as key and value pairs.
+fn entries(table: &'static str) -> impl Iterator<Item = (&'static str, &'static str)> {
+    lines(table).map(|line| {
+        line.split_once(": ")
+            .expect("bundled table entries should have a key")
+    })
+}
+
+fn parse_id(id: &str) -> u64 {
+    id.parse().expect("bundled table IDs should be numbers")
+}
+
+fn parse_items(table: &'static str) -> BTreeMap<u64, ItemData> {
+    entries(table)
+        .map(|(index, value)| {
+            let index = parse_id(index);
+            let mut fields = value.split('/');
+            let mut field = || fields.next().expect("bundled item data should be complete");
+
+            let name = field();
+            let price = field().parse().expect("bundled item price should be valid");
+            let ty = field();
+            let (ty, category) = match ty.split_once(' ') {
+                Some((ty, category)) => (
+                    ty,
+                    category
+                        .parse()
+                        .expect("bundled item category should be valid"),
+                ),
+                None => (ty, 0),
+            };
+
+            (
+                index,
+                ItemData {
+                    index,
+                    name,
+                    price,
+                    category: Category::new(ty, category),
+                },
+            )
+        })
+        .collect()
+}
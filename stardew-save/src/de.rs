@@ -0,0 +1,375 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    convert::{TryFrom, TryInto},
+    iter::FromIterator,
+    str::FromStr,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    error::{Error, ErrorKind, Result},
+    friendship::{FarmerPair, Friendship},
+    item::{Item, ItemId},
+    location::Position,
+    player::FishCaught,
+    raw::RawXml,
+};
+
+thread_local! {
+    static WARNINGS: RefCell<Option<Vec<Error>>> = const { RefCell::new(None) };
+}
+
+/// Enables lenient mode for the current thread until dropped, even if parsing panics.
+pub(crate) struct LenientGuard;
+
+impl LenientGuard {
+    pub(crate) fn new() -> Self {
+        WARNINGS.with(|w| *w.borrow_mut() = Some(Vec::new()));
+        Self
+    }
+
+    pub(crate) fn finish(self) -> Vec<Error> {
+        WARNINGS.with(|w| w.borrow_mut().take()).unwrap_or_default()
+    }
+}
+
+impl Drop for LenientGuard {
+    fn drop(&mut self) {
+        WARNINGS.with(|w| *w.borrow_mut() = None);
+    }
+}
+
+/// Records the error as warning in lenient mode, or hands it back in strict mode.
+pub(crate) fn warn(error: Error) -> Result<()> {
+    WARNINGS.with(|w| match w.borrow_mut().as_mut() {
+        Some(warnings) => {
+            warnings.push(error);
+            Ok(())
+        }
+        None => Err(error),
+    })
+}
+
+pub(crate) fn recover<T: Default>(result: Result<T>) -> Result<T> {
+    result.or_else(|e| warn(e).map(|()| T::default()))
+}
+
+pub(crate) fn collect_recoverable<C, T>(items: impl Iterator<Item = Result<T>>) -> Result<C>
+where
+    C: FromIterator<T>,
+{
+    items
+        .filter_map(|item| match item {
+            Ok(item) => Some(Ok(item)),
+            Err(e) => warn(e).err().map(Err),
+        })
+        .collect()
+}
+
+pub(crate) const XSI: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+pub(crate) fn xsi_type<'a>(value: Node<'a, 'a>) -> Option<&'a str> {
+    value.attribute((XSI, "type"))
+}
+
+pub(crate) fn is_nil(value: Node<'_, '_>) -> bool {
+    value
+        .attribute((XSI, "nil"))
+        .map(|a| a == "true")
+        .unwrap_or_default()
+}
+
+pub(crate) fn get_optional<'a>(value: Node<'a, 'a>, name: &str) -> Option<Node<'a, 'a>> {
+    value
+        .children()
+        .find(|c| c.is_element() && c.tag_name().name() == name)
+}
+
+pub(crate) fn get<'a>(value: Node<'a, 'a>, name: &str) -> Result<Node<'a, 'a>> {
+    get_optional(value, name).ok_or_else(|| Error::missing(value, name))
+}
+
+pub(crate) fn get_content<'a>(value: Node<'a, 'a>) -> Result<&'a str> {
+    value
+        .text()
+        .ok_or_else(|| Error::at(value, ErrorKind::Empty))
+}
+
+pub(crate) fn get_string(value: Node<'_, '_>, name: &str) -> Result<String> {
+    recover(get(value, name).and_then(|v| get_content(v).map(ToOwned::to_owned)))
+}
+
+pub(crate) fn get_optional_string(value: Node<'_, '_>, name: &str) -> Option<String> {
+    get_optional(value, name)?.text().map(ToOwned::to_owned)
+}
+
+pub(crate) fn get_bool(value: Node<'_, '_>, name: &str) -> Result<bool> {
+    recover(get(value, name).and_then(|v| Ok(get_content(v)? == "true")))
+}
+
+pub(crate) fn parse_content<T, E>(value: Node<'_, '_>) -> Result<T>
+where
+    T: FromStr<Err = E>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let content = get_content(value)?;
+    content.parse().map_err(|e| {
+        Error::at(
+            value,
+            ErrorKind::Invalid {
+                content: content.to_owned(),
+                reason: Box::new(e),
+            },
+        )
+    })
+}
+
+pub(crate) fn parse<T, E>(value: Node<'_, '_>, name: &str) -> Result<T>
+where
+    T: FromStr<Err = E> + Default,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    recover(get(value, name).and_then(parse_content))
+}
+
+pub(crate) fn get_wrapped<T: FromElement + Default>(value: Node<'_, '_>, name: &str) -> Result<T> {
+    recover(get(value, name).and_then(unwrap_element))
+}
+
+pub(crate) fn unwrap_element<T: FromElement>(value: Node<'_, '_>) -> Result<T> {
+    let child = value
+        .first_element_child()
+        .ok_or_else(|| Error::at(value, ErrorKind::Empty))?;
+
+    T::from_element(child)
+}
+
+pub(crate) fn try_into<'a, T>(value: Node<'a, 'a>, name: &str) -> Result<T>
+where
+    T: TryFrom<Node<'a, 'a>, Error = Error> + Default,
+{
+    recover(get(value, name).and_then(TryInto::try_into))
+}
+
+pub(crate) fn try_into_optional<'a, T>(value: Node<'a, 'a>, name: &str) -> Result<Option<T>>
+where
+    T: TryFrom<(Option<&'a str>, Node<'a, 'a>), Error = Error>,
+{
+    match get_optional(value, name) {
+        Some(node) if !is_nil(node) => recover((xsi_type(node), node).try_into().map(Some)),
+        _ => Ok(None),
+    }
+}
+
+/// Elements that aren't modeled are kept as is. Some of them only exist in certain versions of
+/// the game, so a missing element is simply empty.
+pub(crate) fn get_raw(value: Node<'_, '_>, name: &str) -> Result<RawXml> {
+    get_optional(value, name).map_or_else(|| Ok(RawXml::default()), RawXml::try_from)
+}
+
+pub(crate) fn ensure_tag(value: Node<'_, '_>, tag: &str) -> Result<()> {
+    let name = value.tag_name().name();
+    if name == tag {
+        Ok(())
+    } else {
+        Err(Error::at(
+            value,
+            ErrorKind::UnexpectedTag {
+                expected: tag.to_owned(),
+                found: name.to_owned(),
+            },
+        ))
+    }
+}
+
+pub(crate) fn try_into_list<'a, T>(value: Node<'a, 'a>, name: &str, tag: &str) -> Result<Vec<T>>
+where
+    T: TryFrom<(Option<&'a str>, Node<'a, 'a>), Error = Error>,
+{
+    let list = match get(value, name) {
+        Ok(list) => list,
+        Err(e) => return recover(Err(e)),
+    };
+
+    collect_recoverable(
+        list.children()
+            .filter(|c| c.is_element() && !is_nil(*c))
+            .map(|c| {
+                ensure_tag(c, tag)?;
+                (xsi_type(c), c).try_into()
+            }),
+    )
+}
+
+pub(crate) fn try_into_optional_list<'a, T>(
+    value: Node<'a, 'a>,
+    name: &str,
+    tag: &str,
+) -> Result<Vec<T>>
+where
+    T: TryFrom<(Option<&'a str>, Node<'a, 'a>), Error = Error>,
+{
+    if get_optional(value, name).is_none() {
+        return Ok(Vec::new());
+    }
+
+    try_into_list(value, name, tag)
+}
+
+pub(crate) fn get_list<T, E, F>(
+    value: Node<'_, '_>,
+    name: &str,
+    tag: &str,
+    transform: F,
+) -> Result<Vec<T>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+    F: Fn(&str) -> Result<T, E>,
+{
+    let list = match get(value, name) {
+        Ok(list) => list,
+        Err(e) => return recover(Err(e)),
+    };
+
+    collect_recoverable(list.children().filter(|c| c.is_element()).map(|c| {
+        ensure_tag(c, tag)?;
+        let content = get_content(c)?;
+
+        transform(content).map_err(|e| {
+            Error::at(
+                c,
+                ErrorKind::Invalid {
+                    content: content.to_owned(),
+                    reason: Box::new(e),
+                },
+            )
+        })
+    }))
+}
+
+pub(crate) fn get_int_list(value: Node<'_, '_>, name: &str) -> Result<Vec<u64>> {
+    get_list(value, name, "int", str::parse)
+}
+
+pub(crate) fn get_string_list_with_tag(
+    value: Node<'_, '_>,
+    name: &str,
+    tag: &str,
+) -> Result<Vec<String>> {
+    get_list(value, name, tag, |value| {
+        Ok::<_, std::convert::Infallible>(value.to_owned())
+    })
+}
+
+pub(crate) fn get_string_list(value: Node<'_, '_>, name: &str) -> Result<Vec<String>> {
+    get_string_list_with_tag(value, name, "string")
+}
+
+pub(crate) trait FromElement: Sized {
+    fn from_element(value: Node<'_, '_>) -> Result<Self>;
+}
+
+impl FromElement for String {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        Ok(value.text().unwrap_or_default().to_owned())
+    }
+}
+
+impl FromElement for bool {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        Ok(value.text() == Some("true"))
+    }
+}
+
+macro_rules! impl_from_element_for_primitives {
+    ($($ty:ty),+) => {
+        $(
+            impl FromElement for $ty {
+                fn from_element(value: Node<'_, '_>) -> Result<Self> {
+                    parse_content(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_element_for_primitives!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+macro_rules! impl_from_element_for_nodes {
+    ($($ty:ty),+) => {
+        $(
+            impl FromElement for $ty {
+                fn from_element(value: Node<'_, '_>) -> Result<Self> {
+                    value.try_into()
+                }
+            }
+        )+
+    };
+}
+
+impl_from_element_for_nodes!(Position, FishCaught, Friendship, FarmerPair);
+
+impl FromElement for ItemId {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        parse_content(value)
+    }
+}
+
+impl FromElement for Item {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        (xsi_type(value), value).try_into()
+    }
+}
+
+impl<T: FromElement> FromElement for Option<T> {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        if is_nil(value) {
+            Ok(None)
+        } else {
+            T::from_element(value).map(Some)
+        }
+    }
+}
+
+impl<T: FromElement> FromElement for Vec<T> {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        value
+            .children()
+            .filter(|c| c.is_element())
+            .map(T::from_element)
+            .collect()
+    }
+}
+
+impl<K: FromElement + Ord, V: FromElement> FromElement for BTreeMap<K, V> {
+    fn from_element(value: Node<'_, '_>) -> Result<Self> {
+        collect_dict(value)
+    }
+}
+
+pub(crate) fn get_dict<C, K, V>(value: Node<'_, '_>, name: &str) -> Result<C>
+where
+    C: FromIterator<(K, V)> + Default,
+    K: FromElement,
+    V: FromElement,
+{
+    recover(get(value, name).and_then(collect_dict))
+}
+
+pub(crate) fn collect_dict<C, K, V>(value: Node<'_, '_>) -> Result<C>
+where
+    C: FromIterator<(K, V)>,
+    K: FromElement,
+    V: FromElement,
+{
+    collect_recoverable(value.children().filter(|c| c.is_element()).map(|c| {
+        ensure_tag(c, "item")?;
+
+        Ok((
+            get(c, "key").and_then(unwrap_element)?,
+            get(c, "value").and_then(unwrap_element)?,
+        ))
+    }))
+}
@@ -0,0 +1,250 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+};
+
+use crate::{item::Item, player::Player, save_game::SaveGame};
+
+const SKILLS: [&str; 6] = ["farming", "fishing", "foraging", "mining", "combat", "luck"];
+
+/// A single difference between two snapshots of the same save game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change {
+    /// Name of the farmer the change belongs to, or empty for changes to the world itself.
+    pub farmer: String,
+    pub field: String,
+    pub kind: ChangeKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    Changed { old: String, new: String },
+    Added(String),
+    Removed(String),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.farmer.is_empty() {
+            write!(f, "{}: ", self.farmer)?;
+        }
+
+        match &self.kind {
+            ChangeKind::Changed { old, new } => write!(f, "{} {} -> {}", self.field, old, new),
+            ChangeKind::Added(entry) => write!(f, "{} + {}", self.field, entry),
+            ChangeKind::Removed(entry) => write!(f, "{} - {}", self.field, entry),
+        }
+    }
+}
+
+/// Compare two snapshots of a save game, usually an older backup with the current save.
+///
+/// Farmers are matched by their multiplayer ID, so renamed farmers are still compared with each
+/// other.
+pub fn diff(old: &SaveGame, new: &SaveGame) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut world = Differ {
+        farmer: "",
+        changes: &mut changes,
+    };
+
+    world.value("year", &old.year, &new.year);
+    world.value("current_season", &old.current_season, &new.current_season);
+    world.value("day_of_month", &old.day_of_month, &new.day_of_month);
+    world.value("game_version", &old.game_version, &new.game_version);
+    world.value(
+        "mine_lowest_level_reached",
+        &old.mine_lowest_level_reached,
+        &new.mine_lowest_level_reached,
+    );
+    world.farmers(old, new);
+
+    for new_farmer in new.farmers() {
+        let old_farmer = old
+            .farmers()
+            .find(|farmer| farmer.unique_multiplayer_id == new_farmer.unique_multiplayer_id);
+
+        if let Some(old_farmer) = old_farmer {
+            diff_farmer(&mut changes, old_farmer, new_farmer);
+        }
+    }
+
+    changes
+}
+
+fn diff_farmer(changes: &mut Vec<Change>, old: &Player, new: &Player) {
+    let mut d = Differ {
+        farmer: &new.name,
+        changes,
+    };
+
+    d.value("name", &old.name, &new.name);
+    d.value("money", &old.money, &new.money);
+    d.value(
+        "total_money_earned",
+        &old.total_money_earned,
+        &new.total_money_earned,
+    );
+    d.value("farming_level", &old.farming_level, &new.farming_level);
+    d.value("fishing_level", &old.fishing_level, &new.fishing_level);
+    d.value("foraging_level", &old.foraging_level, &new.foraging_level);
+    d.value("mining_level", &old.mining_level, &new.mining_level);
+    d.value("combat_level", &old.combat_level, &new.combat_level);
+    d.value("luck_level", &old.luck_level, &new.luck_level);
+
+    for ((skill, old), new) in SKILLS
+        .iter()
+        .zip(&old.experience_points)
+        .zip(&new.experience_points)
+    {
+        d.value(&format!("{}_experience", skill), old, new);
+    }
+
+    d.value("max_items", &old.max_items, &new.max_items);
+    d.value(
+        "house_upgrade_level",
+        &old.house_upgrade_level,
+        &new.house_upgrade_level,
+    );
+    d.value("days_married", &old.days_married, &new.days_married);
+
+    d.items(&old.items, &new.items);
+    d.entries("events_seen", &old.events_seen, &new.events_seen);
+    d.entries("mail_received", &old.mail_received, &new.mail_received);
+    d.entries(
+        "cooking_recipes",
+        old.cooking_recipes.keys(),
+        new.cooking_recipes.keys(),
+    );
+    d.entries(
+        "crafting_recipes",
+        old.crafting_recipes.keys(),
+        new.crafting_recipes.keys(),
+    );
+
+    d.entries(
+        "friendship_data",
+        old.friendship_data.keys(),
+        new.friendship_data.keys(),
+    );
+    for (name, new) in &new.friendship_data {
+        if let Some(old) = old.friendship_data.get(name) {
+            d.value(
+                &format!("friendship_data.{}.points", name),
+                &old.points,
+                &new.points,
+            );
+            d.value(
+                &format!("friendship_data.{}.status", name),
+                &old.status,
+                &new.status,
+            );
+        }
+    }
+}
+
+struct Differ<'a> {
+    farmer: &'a str,
+    changes: &'a mut Vec<Change>,
+}
+
+impl Differ<'_> {
+    fn push(&mut self, field: &str, kind: ChangeKind) {
+        self.changes.push(Change {
+            farmer: self.farmer.to_owned(),
+            field: field.to_owned(),
+            kind,
+        });
+    }
+
+    fn value<T: Display + PartialEq>(&mut self, field: &str, old: &T, new: &T) {
+        if old != new {
+            self.push(
+                field,
+                ChangeKind::Changed {
+                    old: old.to_string(),
+                    new: new.to_string(),
+                },
+            );
+        }
+    }
+
+    /// Compare two collections as sets, reporting entries that only exist on one side.
+    fn entries<'v, T>(
+        &mut self,
+        field: &str,
+        old: impl IntoIterator<Item = &'v T>,
+        new: impl IntoIterator<Item = &'v T>,
+    ) where
+        T: Display + Ord + 'v,
+    {
+        let old = old.into_iter().collect::<BTreeSet<_>>();
+        let new = new.into_iter().collect::<BTreeSet<_>>();
+
+        for removed in old.difference(&new) {
+            self.push(field, ChangeKind::Removed(removed.to_string()));
+        }
+        for added in new.difference(&old) {
+            self.push(field, ChangeKind::Added(added.to_string()));
+        }
+    }
+
+    /// Farmers that joined or left, matched by their multiplayer ID. Renames are left to the
+    /// farmer's own changes.
+    fn farmers(&mut self, old: &SaveGame, new: &SaveGame) {
+        let old_ids = farmer_ids(old);
+        let new_ids = farmer_ids(new);
+
+        for farmer in old.farmers() {
+            if !new_ids.contains(farmer.unique_multiplayer_id.as_str()) {
+                self.push("farmers", ChangeKind::Removed(farmer.name.clone()));
+            }
+        }
+        for farmer in new.farmers() {
+            if !old_ids.contains(farmer.unique_multiplayer_id.as_str()) {
+                self.push("farmers", ChangeKind::Added(farmer.name.clone()));
+            }
+        }
+    }
+
+    /// Items are compared by the total amount of each item, regardless of the inventory slot
+    /// it's in.
+    fn items(&mut self, old: &[Item], new: &[Item]) {
+        let count = |items: &[Item]| {
+            items.iter().fold(BTreeMap::new(), |mut counts, item| {
+                *counts.entry(item.name.clone()).or_insert(0) += item.stack;
+                counts
+            })
+        };
+        let old = count(old);
+        let new = count(new);
+
+        for (name, &old_stack) in &old {
+            let new_stack = new.get(name).copied().unwrap_or_default();
+            if new_stack < old_stack {
+                self.push(
+                    "items",
+                    ChangeKind::Removed(format!("{} x{}", name, old_stack - new_stack)),
+                );
+            }
+        }
+        for (name, &new_stack) in &new {
+            let old_stack = old.get(name).copied().unwrap_or_default();
+            if new_stack > old_stack {
+                self.push(
+                    "items",
+                    ChangeKind::Added(format!("{} x{}", name, new_stack - old_stack)),
+                );
+            }
+        }
+    }
+}
+
+fn farmer_ids(save_game: &SaveGame) -> BTreeSet<&str> {
+    save_game
+        .farmers()
+        .map(|farmer| farmer.unique_multiplayer_id.as_str())
+        .collect()
+}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Display};
+
+use roxmltree::{Node, TextPos};
+use thiserror::Error as ThisError;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error that occurred while loading a save game, together with the location of the offending
+/// element in the XML document.
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    /// Path of the element that caused the error, like `SaveGame/player/items/Item[3]/Stack`.
+    /// Elements that share their tag name with siblings carry their zero-based index.
+    pub path: String,
+    pub pos: TextPos,
+}
+
+impl Error {
+    pub(crate) fn at(node: Node<'_, '_>, kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            path: element_path(node),
+            pos: node.document().text_pos_at(node.range().start),
+        }
+    }
+
+    pub(crate) fn missing(node: Node<'_, '_>, name: &str) -> Self {
+        let mut error = Self::at(node, ErrorKind::Missing);
+        error.path.push('/');
+        error.path.push_str(name);
+        error
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            self.kind.fmt(f)
+        } else {
+            write!(f, "{} at {}: {}", self.path, self.pos, self.kind)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<roxmltree::Error> for Error {
+    fn from(error: roxmltree::Error) -> Self {
+        Self {
+            pos: error.pos(),
+            kind: ErrorKind::Xml(error),
+            path: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum ErrorKind {
+    #[error("invalid XML document: {0}")]
+    Xml(roxmltree::Error),
+    #[error("element is missing")]
+    Missing,
+    #[error("element has no content")]
+    Empty,
+    #[error("invalid content `{content}`: {reason}")]
+    Invalid {
+        content: String,
+        reason: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("expected <{expected}> tag but found <{found}>")]
+    UnexpectedTag { expected: String, found: String },
+    #[error("element has no `xsi:type` attribute")]
+    MissingType,
+    #[error("unknown type `{0}`")]
+    UnknownType(String),
+    #[error("expected at least {expected} values but found {found}")]
+    TooFewValues { expected: usize, found: usize },
+    #[error("writing {0} is not supported")]
+    Unsupported(&'static str),
+}
+
+/// Error for string values that don't match any variant of an enum.
+#[derive(Debug, ThisError)]
+#[error("unknown {kind} `{value}`")]
+pub struct UnknownVariant {
+    kind: &'static str,
+    value: String,
+}
+
+impl UnknownVariant {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_owned(),
+        }
+    }
+}
+
+fn element_path(node: Node<'_, '_>) -> String {
+    let mut segments = node
+        .ancestors()
+        .filter(Node::is_element)
+        .map(|n| {
+            let name = n.tag_name().name();
+            let mut siblings = n
+                .parent()
+                .into_iter()
+                .flat_map(|p| p.children())
+                .filter(|c| c.is_element() && c.tag_name().name() == name);
+
+            if siblings.clone().nth(1).is_some() {
+                let index = siblings.position(|c| c == n).unwrap_or_default();
+                format!("{}[{}]", name, index)
+            } else {
+                name.to_owned()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    segments.reverse();
+    segments.join("/")
+}
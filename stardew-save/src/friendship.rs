@@ -0,0 +1,171 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{get_bool, parse, try_into_optional},
+    error::{Error, Result, UnknownVariant},
+    save_game::Season,
+    ser::{set, set_optional, Emit, Patcher},
+};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Friendship {
+    pub points: i32,
+    pub gifts_this_week: u8,
+    pub gifts_today: u8,
+    pub last_gift_date: Option<WorldDate>,
+    pub talked_to_today: bool,
+    pub proposal_rejected: bool,
+    pub wedding_date: Option<WorldDate>,
+    pub next_birthing_date: Option<WorldDate>,
+    pub status: FriendshipStatus,
+    pub proposer: i64,
+    pub roommate_marriage: bool,
+}
+
+impl Friendship {
+    pub const POINTS_PER_HEART: i32 = 250;
+
+    pub fn hearts(&self) -> u8 {
+        (self.points.max(0) / Self::POINTS_PER_HEART) as u8
+    }
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for Friendship {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            points: parse(value, "Points")?,
+            gifts_this_week: parse(value, "GiftsThisWeek")?,
+            gifts_today: parse(value, "GiftsToday")?,
+            last_gift_date: try_into_optional(value, "LastGiftDate")?,
+            talked_to_today: get_bool(value, "TalkedToToday")?,
+            proposal_rejected: get_bool(value, "ProposalRejected")?,
+            wedding_date: try_into_optional(value, "WeddingDate")?,
+            next_birthing_date: try_into_optional(value, "NextBirthingDate")?,
+            status: parse(value, "Status")?,
+            proposer: parse(value, "Proposer")?,
+            roommate_marriage: get_bool(value, "RoommateMarriage")?,
+        })
+    }
+}
+
+impl Emit for Friendship {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "Points", &self.points)?;
+        set(p, value, "GiftsThisWeek", &self.gifts_this_week)?;
+        set(p, value, "GiftsToday", &self.gifts_today)?;
+        set_optional(p, value, "LastGiftDate", &self.last_gift_date)?;
+        set(p, value, "TalkedToToday", &self.talked_to_today)?;
+        set(p, value, "ProposalRejected", &self.proposal_rejected)?;
+        set_optional(p, value, "WeddingDate", &self.wedding_date)?;
+        set_optional(p, value, "NextBirthingDate", &self.next_birthing_date)?;
+        set(p, value, "Status", &self.status)?;
+        set(p, value, "Proposer", &self.proposer)?;
+        set(p, value, "RoommateMarriage", &self.roommate_marriage)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FriendshipStatus {
+    #[default]
+    Friendly,
+    Dating,
+    Engaged,
+    Married,
+    Divorced,
+}
+
+impl FromStr for FriendshipStatus {
+    type Err = UnknownVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Friendly" => Self::Friendly,
+            "Dating" => Self::Dating,
+            "Engaged" => Self::Engaged,
+            "Married" => Self::Married,
+            "Divorced" => Self::Divorced,
+            _ => return Err(UnknownVariant::new("friendship status", s)),
+        })
+    }
+}
+
+impl Display for FriendshipStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Friendly => "Friendly",
+            Self::Dating => "Dating",
+            Self::Engaged => "Engaged",
+            Self::Married => "Married",
+            Self::Divorced => "Divorced",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FarmerPair {
+    pub farmer1: i64,
+    pub farmer2: i64,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for FarmerPair {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            farmer1: parse(value, "Farmer1")?,
+            farmer2: parse(value, "Farmer2")?,
+        })
+    }
+}
+
+impl Emit for FarmerPair {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "Farmer1", &self.farmer1)?;
+        set(p, value, "Farmer2", &self.farmer2)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorldDate {
+    pub year: u32,
+    pub season: Season,
+    pub day_of_month: u8,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for WorldDate {
+    type Error = Error;
+
+    fn try_from((_, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: parse(value, "Year")?,
+            season: parse(value, "Season")?,
+            day_of_month: parse(value, "DayOfMonth")?,
+        })
+    }
+}
+
+impl Emit for WorldDate {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "Year", &self.year)?;
+        set(p, value, "Season", &self.season)?;
+        set(p, value, "DayOfMonth", &self.day_of_month)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{ensure_tag, get_optional, get_string, parse, parse_content},
+    error::{Error, Result, UnknownVariant},
+    save_game::Season,
+    version::GameVersion,
+};
+
+/// Summary of the host farmer that the game keeps next to the save in the `SaveGameInfo` file,
+/// to show it in the load menu without reading the whole save.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SaveGameInfo {
+    pub name: String,
+    pub farm_name: String,
+    pub money: u64,
+    pub total_money_earned: u64,
+    pub milliseconds_played: u64,
+    pub day_of_month: u8,
+    pub season: Season,
+    pub year: u32,
+    pub farming_level: u8,
+    pub mining_level: u8,
+    pub combat_level: u8,
+    pub foraging_level: u8,
+    pub fishing_level: u8,
+    pub luck_level: u8,
+    pub game_version: Option<GameVersion>,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for SaveGameInfo {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        ensure_tag(value, "Farmer")?;
+
+        Ok(Self {
+            name: get_string(value, "name")?,
+            farm_name: get_string(value, "farmName")?,
+            money: parse(value, "money")?,
+            total_money_earned: parse(value, "totalMoneyEarned")?,
+            milliseconds_played: parse(value, "millisecondsPlayed")?,
+            day_of_month: parse(value, "dayOfMonthForSaveGame")?,
+            season: parse::<SeasonIndex, _>(value, "seasonForSaveGame")?.0,
+            year: parse(value, "yearForSaveGame")?,
+            farming_level: parse(value, "farmingLevel")?,
+            mining_level: parse(value, "miningLevel")?,
+            combat_level: parse(value, "combatLevel")?,
+            foraging_level: parse(value, "foragingLevel")?,
+            fishing_level: parse(value, "fishingLevel")?,
+            luck_level: parse(value, "luckLevel")?,
+            game_version: get_optional(value, "gameVersion")
+                .map(parse_content)
+                .transpose()?,
+        })
+    }
+}
+
+/// The season is stored as number here, instead of its name like in the save itself.
+#[derive(Default)]
+struct SeasonIndex(Season);
+
+impl FromStr for SeasonIndex {
+    type Err = UnknownVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(match s {
+            "0" => Season::Spring,
+            "1" => Season::Summer,
+            "2" => Season::Fall,
+            "3" => Season::Winter,
+            _ => return Err(UnknownVariant::new("season", s)),
+        }))
+    }
+}
@@ -0,0 +1,790 @@
+use std::{
+    convert::{Infallible, TryFrom, TryInto},
+    fmt::{self, Display},
+    mem::discriminant,
+    str::FromStr,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    data::{GameData, ItemData},
+    de::{
+        get_bool, get_optional, get_raw, get_string, is_nil, parse, try_into, try_into_list,
+        try_into_optional_list, xsi_type,
+    },
+    error::{Error, ErrorKind, Result, UnknownVariant},
+    raw::RawXml,
+    ser::{set, set_list, set_omitted, set_optional_list, set_raw, unsupported, Emit, Patcher},
+    version::{since, GameVersion},
+};
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+    pub packed_value: u32,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for Color {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            r: parse(value, "R")?,
+            g: parse(value, "G")?,
+            b: parse(value, "B")?,
+            a: parse(value, "A")?,
+            packed_value: parse(value, "PackedValue")?,
+        })
+    }
+}
+
+impl Emit for Color {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "R", &self.r)?;
+        set(p, value, "G", &self.g)?;
+        set(p, value, "B", &self.b)?;
+        set(p, value, "A", &self.a)?;
+        set(p, value, "PackedValue", &self.packed_value)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClothingItem {
+    pub is_lost_item: bool,
+    pub category: i64,
+    pub has_been_in_inventory: bool,
+    pub name: String,
+    pub parent_sheet_index: u64,
+    pub special_item: bool,
+    pub special_variable: i64,
+    pub display_name: String,
+    pub name2: String,
+    pub stack: u64,
+    pub price: u64,
+    pub index_in_tile_sheet: u64,
+    pub index_in_tile_sheet_female: i64,
+    pub clothes_type: u64,
+    pub dyeable: bool,
+    pub clothes_color: Color,
+    pub other_data: RawXml,
+    pub is_prismatic: bool,
+    pub price2: u64,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for ClothingItem {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            is_lost_item: get_bool(value, "isLostItem")?,
+            category: parse(value, "category")?,
+            has_been_in_inventory: get_bool(value, "hasBeenInInventory")?,
+            name: get_string(value, "name")?,
+            parent_sheet_index: parse(value, "parentSheetIndex")?,
+            special_item: get_bool(value, "specialItem")?,
+            special_variable: parse(value, "SpecialVariable")?,
+            display_name: get_string(value, "DisplayName")?,
+            name2: get_string(value, "Name")?,
+            stack: parse(value, "Stack")?,
+            price: parse(value, "price")?,
+            index_in_tile_sheet: parse(value, "indexInTileSheet")?,
+            index_in_tile_sheet_female: parse(value, "indexInTileSheetFemale")?,
+            clothes_type: parse(value, "clothesType")?,
+            dyeable: get_bool(value, "dyeable")?,
+            clothes_color: try_into(value, "clothesColor")?,
+            other_data: get_raw(value, "otherData")?,
+            is_prismatic: get_bool(value, "isPrismatic")?,
+            price2: parse(value, "Price")?,
+        })
+    }
+}
+
+impl Emit for ClothingItem {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "isLostItem", &self.is_lost_item)?;
+        set(p, value, "category", &self.category)?;
+        set(p, value, "hasBeenInInventory", &self.has_been_in_inventory)?;
+        set(p, value, "name", &self.name)?;
+        set(p, value, "parentSheetIndex", &self.parent_sheet_index)?;
+        set(p, value, "specialItem", &self.special_item)?;
+        set(p, value, "SpecialVariable", &self.special_variable)?;
+        set(p, value, "DisplayName", &self.display_name)?;
+        set(p, value, "Name", &self.name2)?;
+        set(p, value, "Stack", &self.stack)?;
+        set(p, value, "price", &self.price)?;
+        set(p, value, "indexInTileSheet", &self.index_in_tile_sheet)?;
+        set(
+            p,
+            value,
+            "indexInTileSheetFemale",
+            &self.index_in_tile_sheet_female,
+        )?;
+        set(p, value, "clothesType", &self.clothes_type)?;
+        set(p, value, "dyeable", &self.dyeable)?;
+        set(p, value, "clothesColor", &self.clothes_color)?;
+        set_raw(p, value, "otherData", &self.other_data)?;
+        set(p, value, "isPrismatic", &self.is_prismatic)?;
+        set(p, value, "Price", &self.price2)?;
+
+        Ok(())
+    }
+}
+
+/// Identifier of an item. Up to 1.5 these were plain numeric sprite indices, since 1.6 they are
+/// strings that may be qualified with the item type, like `(O)128` for the object `128`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemId {
+    pub qualifier: Option<String>,
+    pub id: String,
+}
+
+impl ItemId {
+    /// Numeric index of the item, which is what older versions of the game used as ID.
+    pub fn index(&self) -> Option<u64> {
+        self.id.parse().ok()
+    }
+}
+
+impl From<u64> for ItemId {
+    fn from(index: u64) -> Self {
+        Self {
+            qualifier: None,
+            id: index.to_string(),
+        }
+    }
+}
+
+impl FromStr for ItemId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (qualifier, id) = match s.strip_prefix('(').and_then(|s| s.split_once(')')) {
+            Some((qualifier, id)) => (Some(qualifier.to_owned()), id),
+            None => (None, s),
+        };
+
+        Ok(Self {
+            qualifier,
+            id: id.to_owned(),
+        })
+    }
+}
+
+impl Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(qualifier) = &self.qualifier {
+            write!(f, "({})", qualifier)?;
+        }
+        f.write_str(&self.id)
+    }
+}
+
+/// Serialized in its textual form, so it can be used as key in maps.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ItemId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ItemId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'_, str>>::deserialize(deserializer)?;
+        Ok(match s.parse() {
+            Ok(id) => id,
+            Err(e) => match e {},
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Item {
+    pub is_lost_item: bool,
+    pub category: i64,
+    pub has_been_in_inventory: bool,
+    pub name: String,
+    pub special_item: bool,
+    pub special_variable: i64,
+    pub display_name: String,
+    pub name2: String,
+    pub stack: u64,
+    pub item_id: Option<ItemId>,
+    pub ty: ItemType,
+}
+
+impl Item {
+    /// Look up the item in the bundled game data, which only knows objects and big craftables.
+    pub fn data(&self) -> Option<&'static ItemData> {
+        let data = GameData::get();
+
+        if let Some(id) = &self.item_id {
+            return data.item(id);
+        }
+
+        match self.ty {
+            ItemType::Object {
+                parent_sheet_index, ..
+            } => data.object(u64::try_from(parent_sheet_index).ok()?),
+            ItemType::BigCraftable {
+                parent_sheet_index, ..
+            } => data.big_craftable(u64::try_from(parent_sheet_index).ok()?),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for Item {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        // Elements without `xsi:type` are typed by their declared field type, which is a ring
+        // inside combined rings and a plain object everywhere else.
+        let ty = ty.unwrap_or(match value.tag_name().name() {
+            "Ring" => "Ring",
+            _ => "Object",
+        });
+
+        Ok(Self {
+            is_lost_item: get_bool(value, "isLostItem")?,
+            category: parse(value, "category")?,
+            has_been_in_inventory: get_bool(value, "hasBeenInInventory")?,
+            name: get_string(value, "name")?,
+            special_item: get_bool(value, "specialItem")?,
+            special_variable: parse(value, "SpecialVariable")?,
+            display_name: get_string(value, "DisplayName")?,
+            name2: get_string(value, "Name")?,
+            stack: parse(value, "Stack")?,
+            item_id: since(GameVersion::V1_6, || parse(value, "itemId"))?,
+            ty: (ty, value).try_into()?,
+        })
+    }
+}
+
+impl Emit for Item {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        if !Self::try_from((xsi_type(value), value))?
+            .ty
+            .same_type(&self.ty)
+        {
+            return Err(unsupported(value, "a different type of item"));
+        }
+
+        set(p, value, "isLostItem", &self.is_lost_item)?;
+        set(p, value, "category", &self.category)?;
+        set(p, value, "hasBeenInInventory", &self.has_been_in_inventory)?;
+        set(p, value, "name", &self.name)?;
+        set(p, value, "specialItem", &self.special_item)?;
+        set(p, value, "SpecialVariable", &self.special_variable)?;
+        set(p, value, "DisplayName", &self.display_name)?;
+        set(p, value, "Name", &self.name2)?;
+        set(p, value, "Stack", &self.stack)?;
+        // Objects keep a second copy of the stack size that the game reads on its own.
+        if get_optional(value, "stack").is_some() {
+            set(p, value, "stack", &self.stack)?;
+        }
+        if let Some(item_id) = &self.item_id {
+            set(p, value, "itemId", item_id)?;
+        }
+
+        self.ty.emit(p, value)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ItemType {
+    Object {
+        parent_sheet_index: i64,
+        price: i64,
+        quality: Quality,
+        preserve: Option<Preserve>,
+    },
+    BigCraftable {
+        parent_sheet_index: i64,
+        price: i64,
+    },
+    Chest {
+        parent_sheet_index: i64,
+        items: Vec<Item>,
+    },
+    Tool {
+        kind: ToolKind,
+        upgrade_level: u8,
+        enchantments: Vec<Enchantment>,
+        attachments: Vec<Item>,
+    },
+    MeleeWeapon {
+        index: i64,
+        weapon_type: u8,
+        min_damage: u32,
+        max_damage: u32,
+        speed: i32,
+        knockback: f32,
+        crit_chance: f32,
+        crit_multiplier: f32,
+        added_precision: i32,
+        added_defense: i32,
+        upgrade_level: u8,
+        enchantments: Vec<Enchantment>,
+    },
+    Slingshot {
+        index: i64,
+        upgrade_level: u8,
+        enchantments: Vec<Enchantment>,
+        attachments: Vec<Item>,
+    },
+    Ring {
+        index_in_tile_sheet: i64,
+        unique_id: i64,
+    },
+    CombinedRing {
+        rings: Vec<Item>,
+    },
+    Boots {
+        index_in_tile_sheet: i64,
+        defense_bonus: i32,
+        immunity_bonus: i32,
+    },
+    Hat {
+        which: i64,
+    },
+    Clothing {
+        parent_sheet_index: i64,
+        clothes_type: u64,
+        index_in_tile_sheet: u64,
+        dyeable: bool,
+        clothes_color: Color,
+        is_prismatic: bool,
+    },
+    Furniture {
+        parent_sheet_index: i64,
+        furniture_type: u8,
+        rotations: u8,
+        current_rotation: u8,
+    },
+    Other(String),
+}
+
+impl TryFrom<(&str, Node<'_, '_>)> for ItemType {
+    type Error = Error;
+
+    fn try_from((ty, value): (&str, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(match ty {
+            "Object" if get_bool(value, "bigCraftable")? => Self::BigCraftable {
+                parent_sheet_index: parse(value, "parentSheetIndex")?,
+                price: parse(value, "price")?,
+            },
+            "Object" => Self::Object {
+                parent_sheet_index: parse(value, "parentSheetIndex")?,
+                price: parse(value, "price")?,
+                quality: parse(value, "quality")?,
+                preserve: match get_optional(value, "preserve") {
+                    Some(preserve) if !is_nil(preserve) => Some(Preserve {
+                        ty: parse(value, "preserve")?,
+                        parent_sheet_index: parse(value, "preservedParentSheetIndex")?,
+                    }),
+                    _ => None,
+                },
+            },
+            "Chest" => Self::Chest {
+                parent_sheet_index: parse(value, "parentSheetIndex")?,
+                items: try_into_list(value, "items", "Item")?,
+            },
+            "Axe" | "Pickaxe" | "Hoe" | "WateringCan" | "FishingRod" | "Pan" | "Shears"
+            | "MilkPail" | "Wand" | "Lantern" | "Raft" | "GenericTool" => Self::Tool {
+                kind: ToolKind::try_from((ty, value))?,
+                upgrade_level: parse(value, "upgradeLevel")?,
+                enchantments: try_into_optional_list(value, "enchantments", "BaseEnchantment")?,
+                attachments: try_into_optional_list(value, "attachments", "Object")?,
+            },
+            "MeleeWeapon" => Self::MeleeWeapon {
+                index: parse(value, "initialParentTileIndex")?,
+                weapon_type: parse(value, "type")?,
+                min_damage: parse(value, "minDamage")?,
+                max_damage: parse(value, "maxDamage")?,
+                speed: parse(value, "speed")?,
+                knockback: parse(value, "knockback")?,
+                crit_chance: parse(value, "critChance")?,
+                crit_multiplier: parse(value, "critMultiplier")?,
+                added_precision: parse(value, "addedPrecision")?,
+                added_defense: parse(value, "addedDefense")?,
+                upgrade_level: parse(value, "upgradeLevel")?,
+                enchantments: try_into_optional_list(value, "enchantments", "BaseEnchantment")?,
+            },
+            "Slingshot" => Self::Slingshot {
+                index: parse(value, "initialParentTileIndex")?,
+                upgrade_level: parse(value, "upgradeLevel")?,
+                enchantments: try_into_optional_list(value, "enchantments", "BaseEnchantment")?,
+                attachments: try_into_optional_list(value, "attachments", "Object")?,
+            },
+            "Ring" => Self::Ring {
+                index_in_tile_sheet: parse(value, "indexInTileSheet")?,
+                unique_id: parse(value, "uniqueID")?,
+            },
+            "CombinedRing" => Self::CombinedRing {
+                rings: try_into_list(value, "combinedRings", "Ring")?,
+            },
+            "Boots" => Self::Boots {
+                index_in_tile_sheet: parse(value, "indexInTileSheet")?,
+                defense_bonus: parse(value, "defenseBonus")?,
+                immunity_bonus: parse(value, "immunityBonus")?,
+            },
+            "Hat" => Self::Hat {
+                which: parse(value, "which")?,
+            },
+            "Clothing" => Self::Clothing {
+                parent_sheet_index: parse(value, "parentSheetIndex")?,
+                clothes_type: parse(value, "clothesType")?,
+                index_in_tile_sheet: parse(value, "indexInTileSheet")?,
+                dyeable: get_bool(value, "dyeable")?,
+                clothes_color: try_into(value, "clothesColor")?,
+                is_prismatic: get_bool(value, "isPrismatic")?,
+            },
+            "Furniture" | "BedFurniture" | "FishTankFurniture" | "StorageFurniture" | "TV" => {
+                Self::Furniture {
+                    parent_sheet_index: parse(value, "parentSheetIndex")?,
+                    furniture_type: parse(value, "furniture_type")?,
+                    rotations: parse(value, "rotations")?,
+                    current_rotation: parse(value, "currentRotation")?,
+                }
+            }
+            _ => Self::Other(ty.to_owned()),
+        })
+    }
+}
+
+impl ItemType {
+    fn same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Tool { kind, .. }, Self::Tool { kind: other, .. }) => {
+                discriminant(kind) == discriminant(other)
+            }
+            (Self::Other(ty), Self::Other(other)) => ty == other,
+            (ty, other) => discriminant(ty) == discriminant(other),
+        }
+    }
+}
+
+impl Emit for ItemType {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        match self {
+            Self::Object {
+                parent_sheet_index,
+                price,
+                quality,
+                preserve,
+            } => {
+                set(p, value, "parentSheetIndex", parent_sheet_index)?;
+                set(p, value, "price", price)?;
+                set(p, value, "quality", quality)?;
+                set_omitted(
+                    p,
+                    value,
+                    "preserve",
+                    "preservedParentSheetIndex",
+                    &preserve.as_ref().map(|preserve| preserve.ty),
+                )?;
+                if let Some(preserve) = preserve {
+                    set(
+                        p,
+                        value,
+                        "preservedParentSheetIndex",
+                        &preserve.parent_sheet_index,
+                    )?;
+                }
+            }
+            Self::BigCraftable {
+                parent_sheet_index,
+                price,
+            } => {
+                set(p, value, "parentSheetIndex", parent_sheet_index)?;
+                set(p, value, "price", price)?;
+            }
+            Self::Chest {
+                parent_sheet_index,
+                items,
+            } => {
+                set(p, value, "parentSheetIndex", parent_sheet_index)?;
+                set_list(p, value, "items", items)?;
+            }
+            Self::Tool {
+                kind,
+                upgrade_level,
+                enchantments,
+                attachments,
+            } => {
+                kind.emit(p, value)?;
+                set(p, value, "upgradeLevel", upgrade_level)?;
+                set_optional_list(p, value, "enchantments", enchantments)?;
+                set_optional_list(p, value, "attachments", attachments)?;
+            }
+            Self::MeleeWeapon {
+                index,
+                weapon_type,
+                min_damage,
+                max_damage,
+                speed,
+                knockback,
+                crit_chance,
+                crit_multiplier,
+                added_precision,
+                added_defense,
+                upgrade_level,
+                enchantments,
+            } => {
+                set(p, value, "initialParentTileIndex", index)?;
+                set(p, value, "type", weapon_type)?;
+                set(p, value, "minDamage", min_damage)?;
+                set(p, value, "maxDamage", max_damage)?;
+                set(p, value, "speed", speed)?;
+                set(p, value, "knockback", knockback)?;
+                set(p, value, "critChance", crit_chance)?;
+                set(p, value, "critMultiplier", crit_multiplier)?;
+                set(p, value, "addedPrecision", added_precision)?;
+                set(p, value, "addedDefense", added_defense)?;
+                set(p, value, "upgradeLevel", upgrade_level)?;
+                set_optional_list(p, value, "enchantments", enchantments)?;
+            }
+            Self::Slingshot {
+                index,
+                upgrade_level,
+                enchantments,
+                attachments,
+            } => {
+                set(p, value, "initialParentTileIndex", index)?;
+                set(p, value, "upgradeLevel", upgrade_level)?;
+                set_optional_list(p, value, "enchantments", enchantments)?;
+                set_optional_list(p, value, "attachments", attachments)?;
+            }
+            Self::Ring {
+                index_in_tile_sheet,
+                unique_id,
+            } => {
+                set(p, value, "indexInTileSheet", index_in_tile_sheet)?;
+                set(p, value, "uniqueID", unique_id)?;
+            }
+            Self::CombinedRing { rings } => set_list(p, value, "combinedRings", rings)?,
+            Self::Boots {
+                index_in_tile_sheet,
+                defense_bonus,
+                immunity_bonus,
+            } => {
+                set(p, value, "indexInTileSheet", index_in_tile_sheet)?;
+                set(p, value, "defenseBonus", defense_bonus)?;
+                set(p, value, "immunityBonus", immunity_bonus)?;
+            }
+            Self::Hat { which } => set(p, value, "which", which)?,
+            Self::Clothing {
+                parent_sheet_index,
+                clothes_type,
+                index_in_tile_sheet,
+                dyeable,
+                clothes_color,
+                is_prismatic,
+            } => {
+                set(p, value, "parentSheetIndex", parent_sheet_index)?;
+                set(p, value, "clothesType", clothes_type)?;
+                set(p, value, "indexInTileSheet", index_in_tile_sheet)?;
+                set(p, value, "dyeable", dyeable)?;
+                set(p, value, "clothesColor", clothes_color)?;
+                set(p, value, "isPrismatic", is_prismatic)?;
+            }
+            Self::Furniture {
+                parent_sheet_index,
+                furniture_type,
+                rotations,
+                current_rotation,
+            } => {
+                set(p, value, "parentSheetIndex", parent_sheet_index)?;
+                set(p, value, "furniture_type", furniture_type)?;
+                set(p, value, "rotations", rotations)?;
+                set(p, value, "currentRotation", current_rotation)?;
+            }
+            Self::Other(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToolKind {
+    Axe,
+    Pickaxe,
+    Hoe,
+    WateringCan {
+        water_left: i32,
+        is_bottomless: bool,
+    },
+    FishingRod,
+    Pan,
+    Shears,
+    MilkPail,
+    Wand,
+    Lantern,
+    Raft,
+    GenericTool,
+}
+
+impl TryFrom<(&str, Node<'_, '_>)> for ToolKind {
+    type Error = Error;
+
+    fn try_from((ty, value): (&str, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(match ty {
+            "Axe" => Self::Axe,
+            "Pickaxe" => Self::Pickaxe,
+            "Hoe" => Self::Hoe,
+            "WateringCan" => Self::WateringCan {
+                water_left: parse(value, "WaterLeft")?,
+                is_bottomless: get_bool(value, "IsBottomless")?,
+            },
+            "FishingRod" => Self::FishingRod,
+            "Pan" => Self::Pan,
+            "Shears" => Self::Shears,
+            "MilkPail" => Self::MilkPail,
+            "Wand" => Self::Wand,
+            "Lantern" => Self::Lantern,
+            "Raft" => Self::Raft,
+            "GenericTool" => Self::GenericTool,
+            _ => return Err(Error::at(value, ErrorKind::UnknownType(ty.to_owned()))),
+        })
+    }
+}
+
+impl Emit for ToolKind {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        if let Self::WateringCan {
+            water_left,
+            is_bottomless,
+        } = self
+        {
+            set(p, value, "WaterLeft", water_left)?;
+            set(p, value, "IsBottomless", is_bottomless)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enchantment {
+    pub ty: String,
+    pub level: u8,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for Enchantment {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ty: ty
+                .ok_or_else(|| Error::at(value, ErrorKind::MissingType))?
+                .to_owned(),
+            level: parse(value, "level")?,
+        })
+    }
+}
+
+impl Emit for Enchantment {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "level", &self.level)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Quality {
+    #[default]
+    Normal,
+    Silver,
+    Gold,
+    Iridium,
+}
+
+impl FromStr for Quality {
+    type Err = UnknownVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "0" => Self::Normal,
+            "1" => Self::Silver,
+            "2" => Self::Gold,
+            "4" => Self::Iridium,
+            _ => return Err(UnknownVariant::new("quality", s)),
+        })
+    }
+}
+
+impl Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Normal => "0",
+            Self::Silver => "1",
+            Self::Gold => "2",
+            Self::Iridium => "4",
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preserve {
+    pub ty: PreserveType,
+    pub parent_sheet_index: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PreserveType {
+    #[default]
+    Wine,
+    Jelly,
+    Pickle,
+    Juice,
+    Roe,
+    AgedRoe,
+    Honey,
+}
+
+impl FromStr for PreserveType {
+    type Err = UnknownVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Wine" => Self::Wine,
+            "Jelly" => Self::Jelly,
+            "Pickle" => Self::Pickle,
+            "Juice" => Self::Juice,
+            "Roe" => Self::Roe,
+            "AgedRoe" => Self::AgedRoe,
+            "Honey" => Self::Honey,
+            _ => return Err(UnknownVariant::new("preserve type", s)),
+        })
+    }
+}
+
+impl Display for PreserveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Wine => "Wine",
+            Self::Jelly => "Jelly",
+            Self::Pickle => "Pickle",
+            Self::Juice => "Juice",
+            Self::Roe => "Roe",
+            Self::AgedRoe => "AgedRoe",
+            Self::Honey => "Honey",
+        })
+    }
+}
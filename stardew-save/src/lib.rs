@@ -0,0 +1,101 @@
+#![allow(dead_code)]
+
+mod bundle;
+mod data;
+mod de;
+mod diff;
+mod error;
+mod friendship;
+mod info;
+mod item;
+mod location;
+mod monsters;
+mod museum;
+mod perfection;
+mod player;
+mod quest;
+mod raw;
+mod save_game;
+mod ser;
+mod version;
+
+use std::convert::TryFrom;
+
+pub use crate::{
+    bundle::{bundles, Bundle, BundleItem, Bundles, Room, RoomProgress, RoomState},
+    data::{Achievement, Category, GameData, ItemData, NpcData},
+    diff::{diff, Change, ChangeKind},
+    error::{Error, ErrorKind, Result, UnknownVariant},
+    friendship::{FarmerPair, Friendship, FriendshipStatus, WorldDate},
+    info::SaveGameInfo,
+    item::{
+        ClothingItem, Color, Enchantment, Item, ItemId, ItemType, Preserve, PreserveType, Quality,
+        ToolKind,
+    },
+    location::{
+        Building, Character, CommunityCenter, Crop, GameLocation, LibraryMuseum, LocationObject,
+        LocationTerrainFeature, LocationType, Position, TerrainFeature,
+    },
+    monsters::{monster_goals, MonsterGoal},
+    museum::{donations, DonationState, Donations, Milestone, MuseumItem},
+    perfection::{perfection, Goal, Perfection, Progress},
+    player::{FishCaught, Player, Stats},
+    quest::{DescriptionElement, Quest, QuestType},
+    raw::RawXml,
+    save_game::{IslandProgress, SaveGame, Season},
+    version::GameVersion,
+};
+use crate::{
+    de::LenientGuard,
+    ser::{Emit, Patcher},
+    version::VersionGuard,
+};
+
+pub fn load(file: &str) -> Result<SaveGame> {
+    let doc = roxmltree::Document::parse(file)?;
+
+    SaveGame::try_from(doc.root_element())
+}
+
+/// Load the `SaveGameInfo` file that is stored next to the save game.
+pub fn load_info(file: &str) -> Result<SaveGameInfo> {
+    let doc = roxmltree::Document::parse(file)?;
+
+    SaveGameInfo::try_from(doc.root_element())
+}
+
+/// Load a save game, falling back to default values for any missing or malformed elements
+/// instead of failing. Every problem that was skipped over is returned as [`Error`] next to the save game.
+///
+/// Only an invalid XML document itself is still reported as error.
+pub fn load_lenient(file: &str) -> Result<(SaveGame, Vec<Error>)> {
+    let doc = roxmltree::Document::parse(file)?;
+
+    let guard = LenientGuard::new();
+    let save_game = SaveGame::try_from(doc.root_element())?;
+
+    Ok((save_game, guard.finish()))
+}
+
+/// Write a save game back into the document it was loaded from.
+///
+/// Only values that changed are patched into the `original` XML, everything else is kept byte for
+/// byte. Elements that aren't modeled yet are available as [`RawXml`] and written back verbatim
+/// if replaced.
+///
+/// New elements can only be written for simple values, so adding entries to lists of complex
+/// values like items or quests, filling in values that were empty, or changing the type of a
+/// value fails with [`ErrorKind::Unsupported`]. Removing entries from lists is fine.
+///
+/// The `original` has to load without problems. Anything [`load_lenient`] skipped over was
+/// replaced with a default, which would otherwise be written over the original content.
+pub fn write(save_game: &SaveGame, original: &str) -> Result<String> {
+    let doc = roxmltree::Document::parse(original)?;
+    SaveGame::try_from(doc.root_element())?;
+
+    let _version = VersionGuard::new(save_game.game_version);
+    let mut patcher = Patcher::new(&doc);
+    save_game.emit(&mut patcher, doc.root_element())?;
+
+    patcher.finish()
+}
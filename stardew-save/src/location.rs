@@ -0,0 +1,610 @@
+use std::{
+    collections::BTreeMap,
+    convert::{TryFrom, TryInto},
+    mem::discriminant,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{
+        get, get_bool, get_dict, get_list, get_optional_string, get_string, parse, try_into,
+        try_into_list, try_into_optional, try_into_optional_list, xsi_type,
+    },
+    error::{Error, Result},
+    item::{Item, ItemId},
+    ser::{
+        set, set_dict, set_list, set_optional, set_optional_list, set_optional_text, set_text_list,
+        unsupported, Emit, Patcher,
+    },
+    version::id_tag,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for Position {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: parse(value, "X")?,
+            y: parse(value, "Y")?,
+        })
+    }
+}
+
+impl Emit for Position {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "X", &self.x)?;
+        set(p, value, "Y", &self.y)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameLocation {
+    pub ty: LocationType,
+    pub name: String,
+    pub unique_name: Option<String>,
+    pub is_farm: bool,
+    pub is_outdoors: bool,
+    pub is_structure: bool,
+    pub characters: Vec<Character>,
+    pub objects: Vec<LocationObject>,
+    pub terrain_features: Vec<LocationTerrainFeature>,
+    pub buildings: Vec<Building>,
+    pub community_center: Option<CommunityCenter>,
+    pub museum: Option<LibraryMuseum>,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for GameLocation {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ty: LocationType::from_type(ty),
+            name: get_string(value, "name")?,
+            unique_name: get_optional_string(value, "uniqueName"),
+            is_farm: get_bool(value, "isFarm")?,
+            is_outdoors: get_bool(value, "isOutdoors")?,
+            is_structure: get_bool(value, "isStructure")?,
+            characters: try_into_list(value, "characters", "NPC")?,
+            objects: try_into_list(value, "objects", "item")?,
+            terrain_features: try_into_list(value, "terrainFeatures", "item")?,
+            buildings: try_into_optional_list(value, "buildings", "Building")?,
+            community_center: match ty {
+                Some("CommunityCenter") => Some(value.try_into()?),
+                _ => None,
+            },
+            museum: match ty {
+                Some("LibraryMuseum") => Some(value.try_into()?),
+                _ => None,
+            },
+        })
+    }
+}
+
+impl Emit for GameLocation {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        if LocationType::from_type(xsi_type(value)) != self.ty {
+            return Err(unsupported(value, "a different type of location"));
+        }
+
+        set(p, value, "name", &self.name)?;
+        set_optional_text(p, value, "uniqueName", self.unique_name.as_deref())?;
+        set(p, value, "isFarm", &self.is_farm)?;
+        set(p, value, "isOutdoors", &self.is_outdoors)?;
+        set(p, value, "isStructure", &self.is_structure)?;
+        set_list(p, value, "characters", &self.characters)?;
+        set_list(p, value, "objects", &self.objects)?;
+        set_list(p, value, "terrainFeatures", &self.terrain_features)?;
+        set_optional_list(p, value, "buildings", &self.buildings)?;
+
+        // The progress of both is stored in the location itself, so it can't be dropped.
+        match &self.community_center {
+            Some(community_center) => community_center.emit(p, value)?,
+            None if self.ty == LocationType::CommunityCenter => {
+                return Err(unsupported(
+                    value,
+                    "a community center without its progress",
+                ));
+            }
+            None => {}
+        }
+        match &self.museum {
+            Some(museum) => museum.emit(p, value),
+            None if self.ty == LocationType::LibraryMuseum => {
+                Err(unsupported(value, "a museum without its donations"))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Items donated to the museum, by the tile they're displayed on.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LibraryMuseum {
+    pub museum_pieces: Vec<(Position, ItemId)>,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for LibraryMuseum {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            museum_pieces: get_dict(value, "museumPieces")?,
+        })
+    }
+}
+
+impl Emit for LibraryMuseum {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set_dict(
+            p,
+            value,
+            "museumPieces",
+            ("Vector2", id_tag()),
+            self.museum_pieces.iter().map(|(tile, item)| (tile, item)),
+        )
+    }
+}
+
+/// Progress of the Community Center restoration, which is only stored in its location.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommunityCenter {
+    pub areas_complete: Vec<bool>,
+    /// Items put into each bundle, by bundle ID and in the order of the bundle's ingredients.
+    pub bundles: BTreeMap<u64, Vec<bool>>,
+    pub bundle_rewards: BTreeMap<u64, bool>,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for CommunityCenter {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            areas_complete: get_list(value, "areasComplete", "boolean", str::parse)?,
+            bundles: get_dict(value, "bundles")?,
+            bundle_rewards: get_dict(value, "bundleRewards")?,
+        })
+    }
+}
+
+impl Emit for CommunityCenter {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set_text_list(p, value, "areasComplete", "boolean", &self.areas_complete)?;
+        set_dict(
+            p,
+            value,
+            "bundles",
+            ("int", "ArrayOfBoolean"),
+            &self.bundles,
+        )?;
+        set_dict(
+            p,
+            value,
+            "bundleRewards",
+            ("int", "boolean"),
+            &self.bundle_rewards,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LocationType {
+    GameLocation,
+    AdventureGuild,
+    AnimalHouse,
+    Beach,
+    BeachNightMarket,
+    BoatTunnel,
+    BugLand,
+    BusStop,
+    Cabin,
+    Caldera,
+    Cellar,
+    Club,
+    CommunityCenter,
+    Desert,
+    Farm,
+    FarmCave,
+    FarmHouse,
+    FishShop,
+    Forest,
+    IslandEast,
+    IslandFarmCave,
+    IslandFarmHouse,
+    IslandFieldOffice,
+    IslandForestLocation,
+    IslandHut,
+    IslandLocation,
+    IslandNorth,
+    IslandSecret,
+    IslandShrine,
+    IslandSouth,
+    IslandSouthEast,
+    IslandSouthEastCave,
+    IslandWest,
+    JojaMart,
+    LibraryMuseum,
+    ManorHouse,
+    MermaidHouse,
+    MineShaft,
+    Mountain,
+    MovieTheater,
+    Railroad,
+    SeedShop,
+    Sewer,
+    Shed,
+    SlimeHutch,
+    Submarine,
+    Summit,
+    Town,
+    WizardHouse,
+    Woods,
+    Other(String),
+}
+
+impl LocationType {
+    fn from_type(ty: Option<&str>) -> Self {
+        match ty {
+            None => Self::GameLocation,
+            Some("AdventureGuild") => Self::AdventureGuild,
+            Some("AnimalHouse") => Self::AnimalHouse,
+            Some("Beach") => Self::Beach,
+            Some("BeachNightMarket") => Self::BeachNightMarket,
+            Some("BoatTunnel") => Self::BoatTunnel,
+            Some("BugLand") => Self::BugLand,
+            Some("BusStop") => Self::BusStop,
+            Some("Cabin") => Self::Cabin,
+            Some("Caldera") => Self::Caldera,
+            Some("Cellar") => Self::Cellar,
+            Some("Club") => Self::Club,
+            Some("CommunityCenter") => Self::CommunityCenter,
+            Some("Desert") => Self::Desert,
+            Some("Farm") => Self::Farm,
+            Some("FarmCave") => Self::FarmCave,
+            Some("FarmHouse") => Self::FarmHouse,
+            Some("FishShop") => Self::FishShop,
+            Some("Forest") => Self::Forest,
+            Some("IslandEast") => Self::IslandEast,
+            Some("IslandFarmCave") => Self::IslandFarmCave,
+            Some("IslandFarmHouse") => Self::IslandFarmHouse,
+            Some("IslandFieldOffice") => Self::IslandFieldOffice,
+            Some("IslandForestLocation") => Self::IslandForestLocation,
+            Some("IslandHut") => Self::IslandHut,
+            Some("IslandLocation") => Self::IslandLocation,
+            Some("IslandNorth") => Self::IslandNorth,
+            Some("IslandSecret") => Self::IslandSecret,
+            Some("IslandShrine") => Self::IslandShrine,
+            Some("IslandSouth") => Self::IslandSouth,
+            Some("IslandSouthEast") => Self::IslandSouthEast,
+            Some("IslandSouthEastCave") => Self::IslandSouthEastCave,
+            Some("IslandWest") => Self::IslandWest,
+            Some("JojaMart") => Self::JojaMart,
+            Some("LibraryMuseum") => Self::LibraryMuseum,
+            Some("ManorHouse") => Self::ManorHouse,
+            Some("MermaidHouse") => Self::MermaidHouse,
+            Some("MineShaft") => Self::MineShaft,
+            Some("Mountain") => Self::Mountain,
+            Some("MovieTheater") => Self::MovieTheater,
+            Some("Railroad") => Self::Railroad,
+            Some("SeedShop") => Self::SeedShop,
+            Some("Sewer") => Self::Sewer,
+            Some("Shed") => Self::Shed,
+            Some("SlimeHutch") => Self::SlimeHutch,
+            Some("Submarine") => Self::Submarine,
+            Some("Summit") => Self::Summit,
+            Some("Town") => Self::Town,
+            Some("WizardHouse") => Self::WizardHouse,
+            Some("Woods") => Self::Woods,
+            Some(ty) => Self::Other(ty.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Character {
+    pub ty: Option<String>,
+    pub name: String,
+    pub position: Position,
+    pub facing_direction: u64,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for Character {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ty: ty.map(ToOwned::to_owned),
+            name: get_string(value, "name")?,
+            position: try_into(value, "Position")?,
+            facing_direction: parse(value, "FacingDirection")?,
+        })
+    }
+}
+
+impl Emit for Character {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        if xsi_type(value) != self.ty.as_deref() {
+            return Err(unsupported(value, "a different type of character"));
+        }
+
+        set(p, value, "name", &self.name)?;
+        set(p, value, "Position", &self.position)?;
+        set(p, value, "FacingDirection", &self.facing_direction)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocationObject {
+    pub tile: Position,
+    pub object: Item,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for LocationObject {
+    type Error = Error;
+
+    fn try_from((_, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        let object = get(get(value, "value")?, "Object")?;
+
+        Ok(Self {
+            tile: try_into(get(value, "key")?, "Vector2")?,
+            object: (xsi_type(object), object).try_into()?,
+        })
+    }
+}
+
+impl Emit for LocationObject {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, get(value, "key")?, "Vector2", &self.tile)?;
+        self.object.emit(p, get(get(value, "value")?, "Object")?)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocationTerrainFeature {
+    pub tile: Position,
+    pub feature: TerrainFeature,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for LocationTerrainFeature {
+    type Error = Error;
+
+    fn try_from((_, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        let feature = get(get(value, "value")?, "TerrainFeature")?;
+
+        Ok(Self {
+            tile: try_into(get(value, "key")?, "Vector2")?,
+            feature: (xsi_type(feature), feature).try_into()?,
+        })
+    }
+}
+
+impl Emit for LocationTerrainFeature {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, get(value, "key")?, "Vector2", &self.tile)?;
+        self.feature
+            .emit(p, get(get(value, "value")?, "TerrainFeature")?)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TerrainFeature {
+    HoeDirt {
+        crop: Option<Crop>,
+    },
+    Tree {
+        tree_type: u64,
+        growth_stage: u8,
+        stump: bool,
+        tapped: bool,
+    },
+    FruitTree {
+        tree_type: u64,
+        growth_stage: u8,
+        days_until_mature: i32,
+        fruits_on_tree: u8,
+    },
+    Grass {
+        number_of_weeds: u8,
+    },
+    Flooring {
+        which_floor: u64,
+    },
+    Bush {
+        size: u8,
+    },
+    Other(String),
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for TerrainFeature {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(match ty {
+            Some("HoeDirt") => Self::HoeDirt {
+                crop: try_into_optional(value, "crop")?,
+            },
+            Some("Tree") => Self::Tree {
+                tree_type: parse(value, "treeType")?,
+                growth_stage: parse(value, "growthStage")?,
+                stump: get_bool(value, "stump")?,
+                tapped: get_bool(value, "tapped")?,
+            },
+            Some("FruitTree") => Self::FruitTree {
+                tree_type: parse(value, "treeType")?,
+                growth_stage: parse(value, "growthStage")?,
+                days_until_mature: parse(value, "daysUntilMature")?,
+                fruits_on_tree: parse(value, "fruitsOnTree")?,
+            },
+            Some("Grass") => Self::Grass {
+                number_of_weeds: parse(value, "numberOfWeeds")?,
+            },
+            Some("Flooring") => Self::Flooring {
+                which_floor: parse(value, "whichFloor")?,
+            },
+            Some("Bush") => Self::Bush {
+                size: parse(value, "size")?,
+            },
+            ty => Self::Other(ty.unwrap_or("TerrainFeature").to_owned()),
+        })
+    }
+}
+
+impl Emit for TerrainFeature {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        let same_type = match (Self::try_from((xsi_type(value), value))?, self) {
+            (Self::Other(original), Self::Other(ty)) => original == *ty,
+            (original, ty) => discriminant(&original) == discriminant(ty),
+        };
+        if !same_type {
+            return Err(unsupported(value, "a different type of terrain feature"));
+        }
+
+        match self {
+            Self::HoeDirt { crop } => set_optional(p, value, "crop", crop)?,
+            Self::Tree {
+                tree_type,
+                growth_stage,
+                stump,
+                tapped,
+            } => {
+                set(p, value, "treeType", tree_type)?;
+                set(p, value, "growthStage", growth_stage)?;
+                set(p, value, "stump", stump)?;
+                set(p, value, "tapped", tapped)?;
+            }
+            Self::FruitTree {
+                tree_type,
+                growth_stage,
+                days_until_mature,
+                fruits_on_tree,
+            } => {
+                set(p, value, "treeType", tree_type)?;
+                set(p, value, "growthStage", growth_stage)?;
+                set(p, value, "daysUntilMature", days_until_mature)?;
+                set(p, value, "fruitsOnTree", fruits_on_tree)?;
+            }
+            Self::Grass { number_of_weeds } => set(p, value, "numberOfWeeds", number_of_weeds)?,
+            Self::Flooring { which_floor } => set(p, value, "whichFloor", which_floor)?,
+            Self::Bush { size } => set(p, value, "size", size)?,
+            Self::Other(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crop {
+    pub index_of_harvest: i64,
+    pub seed_index: i64,
+    pub current_phase: u8,
+    pub day_of_current_phase: u8,
+    pub fully_grown: bool,
+    pub dead: bool,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for Crop {
+    type Error = Error;
+
+    fn try_from((_, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            index_of_harvest: parse(value, "indexOfHarvest")?,
+            seed_index: parse(value, "netSeedIndex")?,
+            current_phase: parse(value, "currentPhase")?,
+            day_of_current_phase: parse(value, "dayOfCurrentPhase")?,
+            fully_grown: get_bool(value, "fullGrown")?,
+            dead: get_bool(value, "dead")?,
+        })
+    }
+}
+
+impl Emit for Crop {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "indexOfHarvest", &self.index_of_harvest)?;
+        set(p, value, "netSeedIndex", &self.seed_index)?;
+        set(p, value, "currentPhase", &self.current_phase)?;
+        set(p, value, "dayOfCurrentPhase", &self.day_of_current_phase)?;
+        set(p, value, "fullGrown", &self.fully_grown)?;
+        set(p, value, "dead", &self.dead)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Building {
+    pub ty: Option<String>,
+    pub building_type: String,
+    pub tile_x: i32,
+    pub tile_y: i32,
+    pub tiles_wide: u32,
+    pub tiles_high: u32,
+    pub days_of_construction_left: u32,
+    pub days_until_upgrade: u32,
+    pub indoors: Option<GameLocation>,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for Building {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ty: ty.map(ToOwned::to_owned),
+            building_type: get_string(value, "buildingType")?,
+            tile_x: parse(value, "tileX")?,
+            tile_y: parse(value, "tileY")?,
+            tiles_wide: parse(value, "tilesWide")?,
+            tiles_high: parse(value, "tilesHigh")?,
+            days_of_construction_left: parse(value, "daysOfConstructionLeft")?,
+            days_until_upgrade: parse(value, "daysUntilUpgrade")?,
+            indoors: try_into_optional(value, "indoors")?,
+        })
+    }
+}
+
+impl Emit for Building {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        if xsi_type(value) != self.ty.as_deref() {
+            return Err(unsupported(value, "a different type of building"));
+        }
+
+        set(p, value, "buildingType", &self.building_type)?;
+        set(p, value, "tileX", &self.tile_x)?;
+        set(p, value, "tileY", &self.tile_y)?;
+        set(p, value, "tilesWide", &self.tiles_wide)?;
+        set(p, value, "tilesHigh", &self.tiles_high)?;
+        set(
+            p,
+            value,
+            "daysOfConstructionLeft",
+            &self.days_of_construction_left,
+        )?;
+        set(p, value, "daysUntilUpgrade", &self.days_until_upgrade)?;
+        set_optional(p, value, "indoors", &self.indoors)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use crate::player::Player;
+
+/// An Adventurer's Guild target, with the monsters that count towards it and the reward Gil
+/// hands out for it.
+struct Target {
+    name: &'static str,
+    goal: u32,
+    monsters: &'static [&'static str],
+    reward: &'static str,
+    /// Mail flag set once the reward was collected.
+    flag: &'static str,
+}
+
+const TARGETS: [Target; 12] = [
+    Target {
+        name: "Slimes",
+        goal: 1000,
+        monsters: &["Green Slime", "Frost Jelly", "Sludge", "Tiger Slime"],
+        reward: "Slime Charmer Ring",
+        flag: "Gil_Slime Charmer Ring",
+    },
+    Target {
+        name: "Void Spirits",
+        goal: 150,
+        monsters: &[
+            "Shadow Guy",
+            "Shadow Shaman",
+            "Shadow Brute",
+            "Shadow Sniper",
+        ],
+        reward: "Savage Ring",
+        flag: "Gil_Savage Ring",
+    },
+    Target {
+        name: "Bats",
+        goal: 200,
+        monsters: &["Bat", "Frost Bat", "Lava Bat", "Iridium Bat"],
+        reward: "Vampire Ring",
+        flag: "Gil_Vampire Ring",
+    },
+    Target {
+        name: "Skeletons",
+        goal: 50,
+        monsters: &["Skeleton", "Skeleton Mage"],
+        reward: "Skeleton Mask",
+        flag: "Gil_Skeleton Mask",
+    },
+    Target {
+        name: "Cave Insects",
+        goal: 125,
+        monsters: &["Bug", "Fly", "Grub", "Mutant Fly", "Mutant Grub"],
+        reward: "Insect Head",
+        flag: "Gil_Insect Head",
+    },
+    Target {
+        name: "Duggies",
+        goal: 30,
+        monsters: &["Duggy", "Magma Duggy"],
+        reward: "Hard Hat",
+        flag: "Gil_Hard Hat",
+    },
+    Target {
+        name: "Dust Sprites",
+        goal: 500,
+        monsters: &["Dust Spirit"],
+        reward: "Burglar's Ring",
+        flag: "Gil_Burglar's Ring",
+    },
+    Target {
+        name: "Rock Crabs",
+        goal: 60,
+        monsters: &["Rock Crab", "Lava Crab", "Iridium Crab"],
+        reward: "Crabshell Ring",
+        flag: "Gil_Crabshell Ring",
+    },
+    Target {
+        name: "Mummies",
+        goal: 100,
+        monsters: &["Mummy"],
+        reward: "Arcane Hat",
+        flag: "Gil_Arcane Hat",
+    },
+    Target {
+        name: "Pepper Rex",
+        goal: 50,
+        monsters: &["Pepper Rex"],
+        reward: "Knight's Helmet",
+        flag: "Gil_Knight's Helmet",
+    },
+    Target {
+        name: "Serpents",
+        goal: 250,
+        monsters: &["Serpent", "Royal Serpent"],
+        reward: "Napalm Ring",
+        flag: "Gil_Napalm Ring",
+    },
+    Target {
+        name: "Magma Sprites",
+        goal: 150,
+        monsters: &["Magma Sprite", "Magma Sparker"],
+        reward: "Marlon's Phone Number",
+        flag: "Gil_Telephone",
+    },
+];
+
+/// Progress of a farmer towards one of the Adventurer's Guild monster eradication goals.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonsterGoal {
+    pub name: String,
+    pub goal: u32,
+    /// Kills of each monster that counts towards the goal.
+    pub monsters: Vec<(String, u32)>,
+    pub reward: String,
+    pub reward_claimed: bool,
+}
+
+impl MonsterGoal {
+    pub fn kills(&self) -> u32 {
+        self.monsters.iter().map(|(_, kills)| kills).sum()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.kills() >= self.goal
+    }
+
+    /// Whether the reward is ready to be picked up from Gil.
+    pub fn reward_available(&self) -> bool {
+        self.is_complete() && !self.reward_claimed
+    }
+}
+
+/// Monster eradication goals of a single farmer, as each farmer has their own kill counts and
+/// collects the rewards from Gil separately.
+pub fn monster_goals(player: &Player) -> Vec<MonsterGoal> {
+    let kills = &player.stats.specific_monsters_killed;
+
+    TARGETS
+        .iter()
+        .map(|target| MonsterGoal {
+            name: target.name.to_owned(),
+            goal: target.goal,
+            monsters: target
+                .monsters
+                .iter()
+                .map(|&monster| {
+                    (
+                        monster.to_owned(),
+                        kills.get(monster).copied().unwrap_or_default(),
+                    )
+                })
+                .collect(),
+            reward: target.reward.to_owned(),
+            reward_claimed: player.mail_received.iter().any(|mail| mail == target.flag),
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    data::{Category, GameData, ItemData},
+    item::ItemId,
+    save_game::SaveGame,
+};
+
+/// Rewards from Gunther that are unlocked by the number of donations, next to the many rewards
+/// for donating specific items.
+const MILESTONES: [(usize, &str); 4] = [
+    (20, "Rarecrow #7"),
+    (40, "Rarecrow #8"),
+    (60, "Rusty Key"),
+    (95, "Stardrop"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DonationState {
+    Donated,
+    /// Found by one of the farmers, but not given to the museum yet.
+    Found,
+    NeverFound,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MuseumItem {
+    pub index: u64,
+    pub name: String,
+    pub state: DonationState,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Milestone {
+    pub donations: usize,
+    pub reward: String,
+    pub reached: bool,
+}
+
+/// Museum collection, split into the two kinds of items that can be donated.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Donations {
+    pub artifacts: Vec<MuseumItem>,
+    pub minerals: Vec<MuseumItem>,
+    pub milestones: Vec<Milestone>,
+}
+
+impl Donations {
+    pub fn items(&self) -> impl Iterator<Item = &MuseumItem> {
+        self.artifacts.iter().chain(&self.minerals)
+    }
+
+    pub fn with_state(&self, state: DonationState) -> impl Iterator<Item = &MuseumItem> {
+        self.items().filter(move |item| item.state == state)
+    }
+
+    pub fn donated(&self) -> usize {
+        self.with_state(DonationState::Donated).count()
+    }
+}
+
+/// Check which artifacts and minerals were donated to the museum, or at least found by any of
+/// the farmers.
+pub fn donations(save_game: &SaveGame) -> Donations {
+    let donated = save_game
+        .locations
+        .iter()
+        .filter_map(|location| location.museum.as_ref())
+        .flat_map(|museum| museum.museum_pieces.iter().map(|(_, id)| id))
+        .filter_map(object_index)
+        .collect::<BTreeSet<_>>();
+    let found = save_game
+        .farmers()
+        .flat_map(|farmer| {
+            farmer
+                .archaelogy_found
+                .keys()
+                .chain(farmer.minerals_found.keys())
+        })
+        .filter_map(object_index)
+        .collect::<BTreeSet<_>>();
+
+    let item = |data: &ItemData| MuseumItem {
+        index: data.index,
+        name: data.name.to_owned(),
+        state: if donated.contains(&data.index) {
+            DonationState::Donated
+        } else if found.contains(&data.index) {
+            DonationState::Found
+        } else {
+            DonationState::NeverFound
+        },
+    };
+    let objects = GameData::get().objects();
+
+    let (artifacts, minerals) = objects
+        .filter(|data| {
+            matches!(
+                data.category,
+                Category::Artifact | Category::Mineral | Category::Gem
+            )
+        })
+        .partition::<Vec<_>, _>(|data| data.category == Category::Artifact);
+
+    let milestones = MILESTONES
+        .iter()
+        .map(|&(donations, reward)| Milestone {
+            donations,
+            reward: reward.to_owned(),
+            reached: donated.len() >= donations,
+        })
+        .collect();
+
+    Donations {
+        artifacts: artifacts.into_iter().map(item).collect(),
+        minerals: minerals.into_iter().map(item).collect(),
+        milestones,
+    }
+}
+
+fn object_index(id: &ItemId) -> Option<u64> {
+    match id.qualifier.as_deref() {
+        None | Some("O") => id.index(),
+        Some(_) => None,
+    }
+}
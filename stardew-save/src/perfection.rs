@@ -0,0 +1,355 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+};
+
+use crate::{
+    data::{Category, GameData},
+    item::ItemId,
+    monsters::monster_goals,
+    player::Player,
+    save_game::SaveGame,
+};
+
+const OBELISKS: [&str; 4] = [
+    "Earth Obelisk",
+    "Water Obelisk",
+    "Desert Obelisk",
+    "Island Obelisk",
+];
+const GOLD_CLOCK: &str = "Gold Clock";
+const MAX_FARMER_LEVEL: usize = 25;
+const GOLDEN_WALNUTS: usize = 130;
+
+/// Mail flags for each stardrop, with where it's found.
+const STARDROPS: [(&str, &str); 7] = [
+    ("CF_Fair", "Stardew Valley Fair"),
+    ("CF_Fish", "Master Angler"),
+    ("CF_Mines", "Mines floor 100"),
+    ("CF_Sewer", "Krobus"),
+    ("CF_Spouse", "Spouse or roommate"),
+    ("CF_Statue", "Old Master Cannoli"),
+    ("museumComplete", "Museum collection"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Goal {
+    ShippedItems,
+    Obelisks,
+    GoldClock,
+    MonsterSlayer,
+    GreatFriends,
+    FarmerLevel,
+    Stardrops,
+    Cooking,
+    Crafting,
+    Fish,
+    GoldenWalnuts,
+}
+
+impl Goal {
+    /// Share of the overall perfection score, in percent.
+    pub fn weight(self) -> f64 {
+        match self {
+            Self::ShippedItems => 15.0,
+            Self::FarmerLevel | Self::GoldenWalnuts => 5.0,
+            Self::Obelisks => 4.0,
+            Self::GreatFriends => 11.0,
+            Self::GoldClock
+            | Self::MonsterSlayer
+            | Self::Stardrops
+            | Self::Cooking
+            | Self::Crafting
+            | Self::Fish => 10.0,
+        }
+    }
+
+    /// Goals that only count once they're fully done, instead of by their progress.
+    fn is_all_or_nothing(self) -> bool {
+        matches!(
+            self,
+            Self::GoldClock | Self::MonsterSlayer | Self::Stardrops
+        )
+    }
+}
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ShippedItems => "Produce & Forage Shipped",
+            Self::Obelisks => "Obelisks on Farm",
+            Self::GoldClock => "Golden Clock on Farm",
+            Self::MonsterSlayer => "Monster Slayer Hero",
+            Self::GreatFriends => "Great Friends",
+            Self::FarmerLevel => "Farmer Level",
+            Self::Stardrops => "Found All Stardrops",
+            Self::Cooking => "Cooking Recipes Made",
+            Self::Crafting => "Crafting Recipes Made",
+            Self::Fish => "Fish Caught",
+            Self::GoldenWalnuts => "Golden Walnuts Found",
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Progress {
+    pub goal: Goal,
+    pub done: usize,
+    pub total: usize,
+    /// What's left to do, like the items that weren't shipped yet.
+    pub missing: Vec<String>,
+}
+
+impl Progress {
+    fn new(goal: Goal, done: usize, total: usize, missing: Vec<String>) -> Self {
+        Self {
+            goal,
+            done: done.min(total),
+            total,
+            missing,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.done >= self.total
+    }
+
+    /// Points this goal adds to the perfection score.
+    pub fn score(&self) -> f64 {
+        if self.total == 0 || self.goal.is_all_or_nothing() && !self.is_complete() {
+            return 0.0;
+        }
+
+        self.goal.weight() * self.done as f64 / self.total as f64
+    }
+}
+
+/// Progress towards perfection, as introduced in 1.5.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Perfection {
+    pub goals: Vec<Progress>,
+}
+
+impl Perfection {
+    pub fn percent(&self) -> f64 {
+        self.goals.iter().map(Progress::score).sum()
+    }
+}
+
+/// Calculate the perfection score of the farm. Like in the game, goals that are tracked for each
+/// farmer only count the farmer that's furthest along, so items shipped by different farmers
+/// don't add up.
+///
+/// The totals are based on the bundled game data, so they can be slightly off compared to the
+/// game itself.
+pub fn perfection(save_game: &SaveGame) -> Perfection {
+    let data = GameData::get();
+    let farmers = save_game.farmers().collect::<Vec<_>>();
+
+    let buildings = save_game
+        .locations
+        .iter()
+        .filter(|location| location.is_farm)
+        .flat_map(|location| &location.buildings)
+        .map(|building| building.building_type.as_str())
+        .collect::<BTreeSet<_>>();
+
+    let goals = vec![
+        best_farmer(&farmers, |farmer| {
+            let shipped = objects(farmer.basic_shipped.keys());
+            collection(
+                Goal::ShippedItems,
+                data.objects()
+                    .filter(|item| is_shipped_category(item.category)),
+                |item| shipped.contains(&item.index),
+                |item| item.name.to_owned(),
+            )
+        }),
+        collection(
+            Goal::Obelisks,
+            OBELISKS.iter(),
+            |obelisk| buildings.contains(*obelisk),
+            |obelisk| obelisk.to_string(),
+        ),
+        collection(
+            Goal::GoldClock,
+            [GOLD_CLOCK].iter(),
+            |clock| buildings.contains(*clock),
+            |clock| clock.to_string(),
+        ),
+        best_farmer(&farmers, monster_slayer),
+        best_farmer(&farmers, |farmer| {
+            collection(
+                Goal::GreatFriends,
+                data.npcs(),
+                |npc| {
+                    farmer
+                        .friendship_data
+                        .get(npc.name)
+                        .is_some_and(|f| f.hearts() >= 10 || npc.datable && f.hearts() >= 8)
+                },
+                |npc| npc.display_name.to_owned(),
+            )
+        }),
+        best_farmer(&farmers, farmer_level),
+        best_farmer(&farmers, |farmer| {
+            collection(
+                Goal::Stardrops,
+                STARDROPS.iter(),
+                |(flag, _)| farmer.mail_received.iter().any(|mail| mail == flag),
+                |(_, source)| source.to_string(),
+            )
+        }),
+        best_farmer(&farmers, |farmer| {
+            let cooked = objects(farmer.recipes_cooked.keys());
+            collection(
+                Goal::Cooking,
+                data.objects()
+                    .filter(|item| item.category == Category::Cooking),
+                |item| cooked.contains(&item.index),
+                |item| item.name.to_owned(),
+            )
+        }),
+        best_farmer(&farmers, |farmer| {
+            collection(
+                Goal::Crafting,
+                data.crafting_recipes(),
+                |recipe| {
+                    farmer
+                        .crafting_recipes
+                        .get(*recipe)
+                        .is_some_and(|&crafted| crafted > 0)
+                },
+                |recipe| recipe.to_string(),
+            )
+        }),
+        best_farmer(&farmers, |farmer| {
+            let caught = objects(farmer.fish_caught.keys());
+            collection(
+                Goal::Fish,
+                data.objects()
+                    .filter(|item| item.category == Category::Fish),
+                |item| caught.contains(&item.index),
+                |item| item.name.to_owned(),
+            )
+        }),
+        golden_walnuts(save_game),
+    ];
+
+    Perfection { goals }
+}
+
+/// Count how many entries of a collection are done, and list the ones that aren't.
+fn collection<T>(
+    goal: Goal,
+    entries: impl Iterator<Item = T>,
+    is_done: impl Fn(&T) -> bool,
+    name: impl Fn(&T) -> String,
+) -> Progress {
+    let mut done = 0;
+    let mut total = 0;
+    let mut missing = Vec::new();
+
+    for entry in entries {
+        total += 1;
+        if is_done(&entry) {
+            done += 1;
+        } else {
+            missing.push(name(&entry));
+        }
+    }
+
+    Progress::new(goal, done, total, missing)
+}
+
+/// Progress of the farmer that's furthest along with a goal.
+fn best_farmer(farmers: &[&Player], progress: impl Fn(&Player) -> Progress) -> Progress {
+    farmers
+        .iter()
+        .map(|farmer| progress(farmer))
+        .max_by_key(|progress| progress.done)
+        .expect("a save always has a host")
+}
+
+fn monster_slayer(farmer: &Player) -> Progress {
+    let goals = monster_goals(farmer);
+
+    collection(
+        Goal::MonsterSlayer,
+        goals.iter(),
+        |goal| goal.is_complete(),
+        |goal| format!("{} ({}/{} kills)", goal.name, goal.kills(), goal.goal),
+    )
+}
+
+/// The farmer level is half of the sum of all skill levels.
+fn farmer_level(farmer: &Player) -> Progress {
+    let levels = [
+        farmer.farming_level,
+        farmer.fishing_level,
+        farmer.foraging_level,
+        farmer.mining_level,
+        farmer.combat_level,
+        farmer.luck_level,
+    ];
+    let level = levels
+        .iter()
+        .map(|&level| usize::from(level))
+        .sum::<usize>()
+        / 2;
+
+    let missing = if level < MAX_FARMER_LEVEL {
+        vec![format!("{} more levels", MAX_FARMER_LEVEL - level)]
+    } else {
+        Vec::new()
+    };
+
+    Progress::new(Goal::FarmerLevel, level, MAX_FARMER_LEVEL, missing)
+}
+
+fn golden_walnuts(save_game: &SaveGame) -> Progress {
+    let found = save_game
+        .island
+        .as_ref()
+        .map_or(0, |island| island.golden_walnuts_found as usize);
+
+    let missing = if found < GOLDEN_WALNUTS {
+        vec![format!("{} more golden walnuts", GOLDEN_WALNUTS - found)]
+    } else {
+        Vec::new()
+    };
+
+    Progress::new(Goal::GoldenWalnuts, found, GOLDEN_WALNUTS, missing)
+}
+
+/// Object indices of a farmer's collection.
+fn objects<'a>(ids: impl Iterator<Item = &'a ItemId>) -> BTreeSet<u64> {
+    ids.filter(|id| matches!(id.qualifier.as_deref(), None | Some("O")))
+        .filter_map(ItemId::index)
+        .collect()
+}
+
+/// Items that are part of the shipping collection, which are mostly crops, forage, animal and
+/// artisan goods and resources.
+fn is_shipped_category(category: Category) -> bool {
+    matches!(
+        category,
+        Category::Egg
+            | Category::Milk
+            | Category::Meat
+            | Category::MetalResource
+            | Category::BuildingResource
+            | Category::SellAtPierresAndMarnies
+            | Category::AnimalProduct
+            | Category::ArtisanGoods
+            | Category::Syrup
+            | Category::MonsterLoot
+            | Category::Vegetable
+            | Category::Fruit
+            | Category::Flower
+            | Category::Forage
+    )
+}
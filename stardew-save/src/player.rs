@@ -0,0 +1,1008 @@
+use std::{
+    collections::BTreeMap,
+    convert::{Infallible, TryFrom},
+    str::FromStr,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{
+        get_bool, get_dict, get_int_list, get_list, get_optional, get_raw, get_string,
+        get_string_list, get_wrapped, parse, try_into, try_into_list, try_into_optional,
+        FromElement,
+    },
+    error::{Error, ErrorKind, Result},
+    friendship::Friendship,
+    item::{ClothingItem, Color, Item, ItemId},
+    location::Position,
+    quest::Quest,
+    raw::RawXml,
+    ser::{
+        set, set_dict, set_list, set_optional, set_raw, set_slot_list, set_text_list, set_wrapped,
+        Emit, Patcher, ToElement,
+    },
+    version::{id_tag, since, until, version, GameVersion},
+};
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    pub name: String,
+    pub is_emoting: bool,
+    pub is_charging: bool,
+    pub is_glowing: bool,
+    pub colored_border: bool,
+    pub flip: bool,
+    pub draw_on_top: bool,
+    pub face_toward_farmer: bool,
+    pub ignore_movement_animation: bool,
+    pub face_away_from_farmer: bool,
+    pub scale: f32,
+    pub time_before_ai_movement_again: u64,
+    pub glowing_transparency: f64,
+    pub glow_rate: f64,
+    pub will_destroy_objects_underfoot: bool,
+    pub position: Position,
+    pub speed: u64,
+    pub facing_direction: u64,
+    pub is_emoting2: bool,
+    pub current_emote: u64,
+    pub scale2: f64,
+    pub quest_log: Vec<Quest>,
+    pub professions: Vec<u64>,
+    pub new_levels: RawXml,
+    pub experience_points: Vec<u64>,
+    pub items: Vec<Item>,
+    pub dialogue_questions_answered: Vec<String>,
+    pub furniture_owned: RawXml,
+    pub cooking_recipes: BTreeMap<String, u32>,
+    pub crafting_recipes: BTreeMap<String, u32>,
+    pub active_dialogue_events: RawXml,
+    pub events_seen: Vec<String>,
+    pub secret_notes_seen: RawXml,
+    pub songs_heard: Vec<String>,
+    pub achievements: Vec<u64>,
+    pub special_items: Vec<ItemId>,
+    pub special_big_craftables: Vec<ItemId>,
+    pub mail_received: Vec<String>,
+    pub mail_for_tomorrow: RawXml,
+    pub mailbox: Vec<String>,
+    pub time_went_to_bed: u64,
+    pub stats: Stats,
+    pub blueprints: RawXml,
+    pub items_lost_last_death: Vec<Item>,
+    pub farm_name: String,
+    pub favorite_thing: String,
+    pub slot_can_host: bool,
+    pub user_id: RawXml,
+    /// Replaced by [`Self::which_pet_type`] in 1.6.
+    pub cat_person: Option<bool>,
+    pub which_pet_type: Option<String>,
+    pub which_pet_breed: u64,
+    pub accepted_daily_quest: bool,
+    pub most_recent_bed: Position,
+    pub performed_emotes: RawXml,
+    pub shirt: i64,
+    pub hair: u64,
+    pub skin: u64,
+    pub shoes: i64,
+    pub accessory: i64,
+    pub facial_hair: i64,
+    pub pants: i64,
+    pub hairstyle_color: Color,
+    pub pants_color: Color,
+    pub new_eye_color: Color,
+    pub shirt_item: ClothingItem,
+    pub pants_item: ClothingItem,
+    pub divorce_tonight: bool,
+    pub change_wallet_type_tonight: bool,
+    pub wood_pieces: u64,
+    pub stone_pieces: u64,
+    pub copper_pieces: u64,
+    pub iron_pieces: u64,
+    pub coal_pieces: u64,
+    pub gold_pieces: u64,
+    pub iridium_pieces: u64,
+    pub quartz_pieces: u64,
+    pub game_version: Option<String>,
+    pub cave_choice: u8,
+    pub feed: u64,
+    pub farming_level: u8,
+    pub mining_level: u8,
+    pub combat_level: u8,
+    pub foraging_level: u8,
+    pub fishing_level: u8,
+    pub luck_level: u8,
+    pub new_skill_points_to_spend: u8,
+    pub added_farming_level: u8,
+    pub added_mining_level: u8,
+    pub added_combat_level: u8,
+    pub added_foraging_level: u8,
+    pub added_fishing_level: u8,
+    pub added_luck_level: u8,
+    pub max_stamina: u32,
+    pub max_items: u32,
+    pub last_seen_movie_week: i32,
+    pub resilience: u64,
+    pub attack: u64,
+    pub immunity: u64,
+    pub attack_increase_modifier: u64,
+    pub knockback_modifier: u64,
+    pub weapon_speed_modifier: u64,
+    pub crit_chance_modifier: u64,
+    pub crit_power_modifier: u64,
+    pub weapon_precision_modifier: u64,
+    pub club_coins: u64,
+    pub trash_can_level: u8,
+    pub tool_being_upgraded: Option<Item>,
+    pub days_left_for_tool_upgrade: u8,
+    pub house_upgrade_level: u8,
+    pub days_until_house_upgrade: i8,
+    pub coop_upgrade_level: u8,
+    pub barn_upgrade_level: u8,
+    pub has_greenhouse: bool,
+    pub has_unlocked_skull_door: bool,
+    pub has_dark_talisman: Option<bool>,
+    pub has_magic_ink: Option<bool>,
+    pub show_chest_color_picker: bool,
+    pub has_magnifying_glass: Option<bool>,
+    pub has_watering_can_enchantment: bool,
+    pub magnetic_radius: u16,
+    pub temporary_invincibility_timer: u64,
+    pub health: u32,
+    pub max_health: u32,
+    pub difficulty_modifier: i32,
+    pub is_male: bool,
+    pub has_bus_ticket: bool,
+    pub stardew_hero: bool,
+    pub has_club_card: Option<bool>,
+    pub has_special_charm: Option<bool>,
+    pub basic_shipped: BTreeMap<ItemId, u32>,
+    pub minerals_found: BTreeMap<ItemId, u32>,
+    pub recipes_cooked: BTreeMap<ItemId, u32>,
+    pub fish_caught: BTreeMap<ItemId, FishCaught>,
+    pub archaelogy_found: BTreeMap<ItemId, Vec<u32>>,
+    pub gifted_items: BTreeMap<String, BTreeMap<ItemId, u32>>,
+    pub tailored_items: BTreeMap<String, u32>,
+    pub friendship_data: BTreeMap<String, Friendship>,
+    pub day_of_month_for_save_game: u8,
+    pub season_for_save_game: u8,
+    pub year_for_safe_game: u32,
+    pub overalls_color: u32,
+    pub shirt_color: u32,
+    pub skin_color: u32,
+    pub hair_color: u32,
+    pub eye_color: u32,
+    pub save_time: u64,
+    pub is_customized: bool,
+    pub home_location: String,
+    pub days_married: u64,
+    pub movement_multiplier: f64,
+    pub theater_build_date: i64,
+    pub deepest_mine_level: u8,
+    pub stamina: u32,
+    pub total_money_earned: u64,
+    pub milliseconds_played: u64,
+    /// The special items like keys are mail flags since 1.6, so these are only set for older
+    /// saves.
+    pub has_rusty_key: Option<bool>,
+    pub has_skull_key: Option<bool>,
+    pub can_understand_dwarves: Option<bool>,
+    pub use_separate_wallets: bool,
+    pub times_reached_mine_bottom: u64,
+    pub unique_multiplayer_id: String,
+    pub money: u64,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for Player {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: get_string(value, "name")?,
+            is_emoting: get_bool(value, "isEmoting")?,
+            is_charging: get_bool(value, "isCharging")?,
+            is_glowing: get_bool(value, "isGlowing")?,
+            colored_border: get_bool(value, "coloredBorder")?,
+            flip: get_bool(value, "flip")?,
+            draw_on_top: get_bool(value, "drawOnTop")?,
+            face_toward_farmer: get_bool(value, "faceTowardFarmer")?,
+            ignore_movement_animation: get_bool(value, "ignoreMovementAnimation")?,
+            face_away_from_farmer: get_bool(value, "faceAwayFromFarmer")?,
+            scale: get_wrapped(value, "scale")?,
+            time_before_ai_movement_again: parse(value, "timeBeforeAIMovementAgain")?,
+            glowing_transparency: parse(value, "glowingTransparency")?,
+            glow_rate: parse(value, "glowRate")?,
+            will_destroy_objects_underfoot: get_bool(value, "willDestroyObjectsUnderfoot")?,
+            position: try_into(value, "Position")?,
+            speed: parse(value, "Speed")?,
+            facing_direction: parse(value, "FacingDirection")?,
+            is_emoting2: get_bool(value, "IsEmoting")?,
+            current_emote: parse(value, "CurrentEmote")?,
+            scale2: parse(value, "Scale")?,
+            quest_log: try_into_list(value, "questLog", "Quest")?,
+            professions: get_int_list(value, "professions")?,
+            new_levels: get_raw(value, "newLevels")?,
+            experience_points: get_int_list(value, "experiencePoints")?,
+            items: try_into_list(value, "items", "Item")?,
+            dialogue_questions_answered: get_id_list(value, "dialogueQuestionsAnswered")?,
+            furniture_owned: get_raw(value, "furnitureOwned")?,
+            cooking_recipes: get_dict(value, "cookingRecipes")?,
+            crafting_recipes: get_dict(value, "craftingRecipes")?,
+            active_dialogue_events: get_raw(value, "activeDialogueEvents")?,
+            events_seen: get_id_list(value, "eventsSeen")?,
+            secret_notes_seen: get_raw(value, "secretNotesSeen")?,
+            songs_heard: get_string_list(value, "songsHeard")?,
+            achievements: get_int_list(value, "achievements")?,
+            special_items: get_id_list(value, "specialItems")?,
+            special_big_craftables: get_id_list(value, "specialBigCraftables")?,
+            mail_received: get_string_list(value, "mailReceived")?,
+            mail_for_tomorrow: get_raw(value, "mailForTomorrow")?,
+            mailbox: get_string_list(value, "mailbox")?,
+            time_went_to_bed: get_wrapped(value, "timeWentToBed")?,
+            stats: try_into(value, "stats")?,
+            blueprints: get_raw(value, "blueprints")?,
+            items_lost_last_death: try_into_list(value, "itemsLostLastDeath", "Item")?,
+            farm_name: get_string(value, "farmName")?,
+            favorite_thing: get_string(value, "favoriteThing")?,
+            slot_can_host: get_bool(value, "slotCanHost")?,
+            user_id: get_raw(value, "userID")?,
+            cat_person: until(GameVersion::V1_6, || get_bool(value, "catPerson"))?,
+            which_pet_type: since(GameVersion::V1_6, || get_string(value, "whichPetType"))?,
+            which_pet_breed: parse(value, "whichPetBreed")?,
+            accepted_daily_quest: get_bool(value, "acceptedDailyQuest")?,
+            most_recent_bed: try_into(value, "mostRecentBed")?,
+            performed_emotes: get_raw(value, "performedEmotes")?,
+            shirt: parse(value, "shirt")?,
+            hair: parse(value, "hair")?,
+            skin: parse(value, "skin")?,
+            shoes: parse(value, "shoes")?,
+            accessory: parse(value, "accessory")?,
+            facial_hair: parse(value, "facialHair")?,
+            pants: parse(value, "pants")?,
+            hairstyle_color: try_into(value, "hairstyleColor")?,
+            pants_color: try_into(value, "pantsColor")?,
+            new_eye_color: try_into(value, "newEyeColor")?,
+            shirt_item: try_into(value, "shirtItem")?,
+            pants_item: try_into(value, "pantsItem")?,
+            divorce_tonight: get_bool(value, "divorceTonight")?,
+            change_wallet_type_tonight: get_bool(value, "changeWalletTypeTonight")?,
+            wood_pieces: parse(value, "woodPieces")?,
+            stone_pieces: parse(value, "stonePieces")?,
+            copper_pieces: parse(value, "copperPieces")?,
+            iron_pieces: parse(value, "ironPieces")?,
+            coal_pieces: parse(value, "coalPieces")?,
+            gold_pieces: parse(value, "goldPieces")?,
+            iridium_pieces: parse(value, "iridiumPieces")?,
+            quartz_pieces: parse(value, "quartzPieces")?,
+            game_version: since(GameVersion::V1_4, || get_string(value, "gameVersion"))?,
+            cave_choice: parse(value, "caveChoice")?,
+            feed: parse(value, "feed")?,
+            farming_level: parse(value, "farmingLevel")?,
+            mining_level: parse(value, "miningLevel")?,
+            combat_level: parse(value, "combatLevel")?,
+            foraging_level: parse(value, "foragingLevel")?,
+            fishing_level: parse(value, "fishingLevel")?,
+            luck_level: parse(value, "luckLevel")?,
+            new_skill_points_to_spend: parse(value, "newSkillPointsToSpend")?,
+            added_farming_level: parse(value, "addedFarmingLevel")?,
+            added_mining_level: parse(value, "addedMiningLevel")?,
+            added_combat_level: parse(value, "addedCombatLevel")?,
+            added_foraging_level: parse(value, "addedForagingLevel")?,
+            added_fishing_level: parse(value, "addedFishingLevel")?,
+            added_luck_level: parse(value, "addedLuckLevel")?,
+            max_stamina: parse(value, "maxStamina")?,
+            max_items: parse(value, "maxItems")?,
+            last_seen_movie_week: parse(value, "lastSeenMovieWeek")?,
+            resilience: parse(value, "resilience")?,
+            attack: parse(value, "attack")?,
+            immunity: parse(value, "immunity")?,
+            attack_increase_modifier: parse(value, "attackIncreaseModifier")?,
+            knockback_modifier: parse(value, "knockbackModifier")?,
+            weapon_speed_modifier: parse(value, "weaponSpeedModifier")?,
+            crit_chance_modifier: parse(value, "critChanceModifier")?,
+            crit_power_modifier: parse(value, "critPowerModifier")?,
+            weapon_precision_modifier: parse(value, "weaponPrecisionModifier")?,
+            club_coins: parse(value, "clubCoins")?,
+            trash_can_level: parse(value, "trashCanLevel")?,
+            tool_being_upgraded: try_into_optional(value, "toolBeingUpgraded")?,
+            days_left_for_tool_upgrade: parse(value, "daysLeftForToolUpgrade")?,
+            house_upgrade_level: parse(value, "houseUpgradeLevel")?,
+            days_until_house_upgrade: parse(value, "daysUntilHouseUpgrade")?,
+            coop_upgrade_level: parse(value, "coopUpgradeLevel")?,
+            barn_upgrade_level: parse(value, "barnUpgradeLevel")?,
+            has_greenhouse: get_bool(value, "hasGreenhouse")?,
+            has_unlocked_skull_door: get_bool(value, "hasUnlockedSkullDoor")?,
+            has_dark_talisman: until(GameVersion::V1_6, || get_bool(value, "hasDarkTalisman"))?,
+            has_magic_ink: until(GameVersion::V1_6, || get_bool(value, "hasMagicInk"))?,
+            show_chest_color_picker: get_bool(value, "showChestColorPicker")?,
+            has_magnifying_glass: until(GameVersion::V1_6, || {
+                get_bool(value, "hasMagnifyingGlass")
+            })?,
+            has_watering_can_enchantment: since(GameVersion::V1_5, || {
+                get_bool(value, "hasWateringCanEnchantment")
+            })?
+            .unwrap_or_default(),
+            magnetic_radius: parse(value, "magneticRadius")?,
+            temporary_invincibility_timer: parse(value, "temporaryInvincibilityTimer")?,
+            health: parse(value, "health")?,
+            max_health: parse(value, "maxHealth")?,
+            difficulty_modifier: parse(value, "difficultyModifier")?,
+            is_male: get_bool(value, "isMale")?,
+            has_bus_ticket: get_bool(value, "hasBusTicket")?,
+            stardew_hero: get_bool(value, "stardewHero")?,
+            has_club_card: until(GameVersion::V1_6, || get_bool(value, "hasClubCard"))?,
+            has_special_charm: until(GameVersion::V1_6, || get_bool(value, "hasSpecialCharm"))?,
+            basic_shipped: get_dict(value, "basicShipped")?,
+            minerals_found: get_dict(value, "mineralsFound")?,
+            recipes_cooked: get_dict(value, "recipesCooked")?,
+            fish_caught: get_dict(value, "fishCaught")?,
+            archaelogy_found: get_dict(value, "archaeologyFound")?,
+            gifted_items: get_dict(value, "giftedItems")?,
+            tailored_items: get_dict(value, "tailoredItems")?,
+            friendship_data: get_dict(value, "friendshipData")?,
+            day_of_month_for_save_game: parse(value, "dayOfMonthForSaveGame")?,
+            season_for_save_game: parse(value, "seasonForSaveGame")?,
+            year_for_safe_game: parse(value, "yearForSaveGame")?,
+            overalls_color: parse(value, "overallsColor")?,
+            shirt_color: parse(value, "shirtColor")?,
+            skin_color: parse(value, "skinColor")?,
+            hair_color: parse(value, "hairColor")?,
+            eye_color: parse(value, "eyeColor")?,
+            save_time: parse(value, "saveTime")?,
+            is_customized: get_bool(value, "isCustomized")?,
+            home_location: get_string(value, "homeLocation")?,
+            days_married: parse(value, "daysMarried")?,
+            movement_multiplier: parse(value, "movementMultiplier")?,
+            theater_build_date: parse(value, "theaterBuildDate")?,
+            deepest_mine_level: parse(value, "deepestMineLevel")?,
+            stamina: parse(value, "stamina")?,
+            total_money_earned: parse(value, "totalMoneyEarned")?,
+            milliseconds_played: parse(value, "millisecondsPlayed")?,
+            has_rusty_key: until(GameVersion::V1_6, || get_bool(value, "hasRustyKey"))?,
+            has_skull_key: until(GameVersion::V1_6, || get_bool(value, "hasSkullKey"))?,
+            can_understand_dwarves: until(GameVersion::V1_6, || {
+                get_bool(value, "canUnderstandDwarves")
+            })?,
+            use_separate_wallets: get_bool(value, "useSeparateWallets")?,
+            times_reached_mine_bottom: parse(value, "timesReachedMineBottom")?,
+            unique_multiplayer_id: get_string(value, "UniqueMultiplayerID")?,
+            money: parse(value, "money")?,
+        })
+    }
+}
+
+impl Emit for Player {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "name", &self.name)?;
+        set(p, value, "isEmoting", &self.is_emoting)?;
+        set(p, value, "isCharging", &self.is_charging)?;
+        set(p, value, "isGlowing", &self.is_glowing)?;
+        set(p, value, "coloredBorder", &self.colored_border)?;
+        set(p, value, "flip", &self.flip)?;
+        set(p, value, "drawOnTop", &self.draw_on_top)?;
+        set(p, value, "faceTowardFarmer", &self.face_toward_farmer)?;
+        set(
+            p,
+            value,
+            "ignoreMovementAnimation",
+            &self.ignore_movement_animation,
+        )?;
+        set(p, value, "faceAwayFromFarmer", &self.face_away_from_farmer)?;
+        set_wrapped(p, value, "scale", &self.scale)?;
+        set(
+            p,
+            value,
+            "timeBeforeAIMovementAgain",
+            &self.time_before_ai_movement_again,
+        )?;
+        set(p, value, "glowingTransparency", &self.glowing_transparency)?;
+        set(p, value, "glowRate", &self.glow_rate)?;
+        set(
+            p,
+            value,
+            "willDestroyObjectsUnderfoot",
+            &self.will_destroy_objects_underfoot,
+        )?;
+        set(p, value, "Position", &self.position)?;
+        set(p, value, "Speed", &self.speed)?;
+        set(p, value, "FacingDirection", &self.facing_direction)?;
+        set(p, value, "IsEmoting", &self.is_emoting2)?;
+        set(p, value, "CurrentEmote", &self.current_emote)?;
+        set(p, value, "Scale", &self.scale2)?;
+        set_list(p, value, "questLog", &self.quest_log)?;
+        set_text_list(p, value, "professions", "int", &self.professions)?;
+        set_raw(p, value, "newLevels", &self.new_levels)?;
+        set_text_list(p, value, "experiencePoints", "int", &self.experience_points)?;
+        set_slot_list(p, value, "items", &self.items)?;
+        set_text_list(
+            p,
+            value,
+            "dialogueQuestionsAnswered",
+            id_tag(),
+            &self.dialogue_questions_answered,
+        )?;
+        set_raw(p, value, "furnitureOwned", &self.furniture_owned)?;
+        set_dict(
+            p,
+            value,
+            "cookingRecipes",
+            ("string", "int"),
+            &self.cooking_recipes,
+        )?;
+        set_dict(
+            p,
+            value,
+            "craftingRecipes",
+            ("string", "int"),
+            &self.crafting_recipes,
+        )?;
+        set_raw(
+            p,
+            value,
+            "activeDialogueEvents",
+            &self.active_dialogue_events,
+        )?;
+        set_text_list(p, value, "eventsSeen", id_tag(), &self.events_seen)?;
+        set_raw(p, value, "secretNotesSeen", &self.secret_notes_seen)?;
+        set_text_list(p, value, "songsHeard", "string", &self.songs_heard)?;
+        set_text_list(p, value, "achievements", "int", &self.achievements)?;
+        set_text_list(p, value, "specialItems", id_tag(), &self.special_items)?;
+        set_text_list(
+            p,
+            value,
+            "specialBigCraftables",
+            id_tag(),
+            &self.special_big_craftables,
+        )?;
+        set_text_list(p, value, "mailReceived", "string", &self.mail_received)?;
+        set_raw(p, value, "mailForTomorrow", &self.mail_for_tomorrow)?;
+        set_text_list(p, value, "mailbox", "string", &self.mailbox)?;
+        set_wrapped(p, value, "timeWentToBed", &self.time_went_to_bed)?;
+        set(p, value, "stats", &self.stats)?;
+        set_raw(p, value, "blueprints", &self.blueprints)?;
+        set_list(p, value, "itemsLostLastDeath", &self.items_lost_last_death)?;
+        set(p, value, "farmName", &self.farm_name)?;
+        set(p, value, "favoriteThing", &self.favorite_thing)?;
+        set(p, value, "slotCanHost", &self.slot_can_host)?;
+        set_raw(p, value, "userID", &self.user_id)?;
+        if let Some(cat_person) = &self.cat_person {
+            set(p, value, "catPerson", cat_person)?;
+        }
+        if let Some(pet_type) = &self.which_pet_type {
+            set(p, value, "whichPetType", pet_type)?;
+        }
+        set(p, value, "whichPetBreed", &self.which_pet_breed)?;
+        set(p, value, "acceptedDailyQuest", &self.accepted_daily_quest)?;
+        set(p, value, "mostRecentBed", &self.most_recent_bed)?;
+        set_raw(p, value, "performedEmotes", &self.performed_emotes)?;
+        set(p, value, "shirt", &self.shirt)?;
+        set(p, value, "hair", &self.hair)?;
+        set(p, value, "skin", &self.skin)?;
+        set(p, value, "shoes", &self.shoes)?;
+        set(p, value, "accessory", &self.accessory)?;
+        set(p, value, "facialHair", &self.facial_hair)?;
+        set(p, value, "pants", &self.pants)?;
+        set(p, value, "hairstyleColor", &self.hairstyle_color)?;
+        set(p, value, "pantsColor", &self.pants_color)?;
+        set(p, value, "newEyeColor", &self.new_eye_color)?;
+        set(p, value, "shirtItem", &self.shirt_item)?;
+        set(p, value, "pantsItem", &self.pants_item)?;
+        set(p, value, "divorceTonight", &self.divorce_tonight)?;
+        set(
+            p,
+            value,
+            "changeWalletTypeTonight",
+            &self.change_wallet_type_tonight,
+        )?;
+        set(p, value, "woodPieces", &self.wood_pieces)?;
+        set(p, value, "stonePieces", &self.stone_pieces)?;
+        set(p, value, "copperPieces", &self.copper_pieces)?;
+        set(p, value, "ironPieces", &self.iron_pieces)?;
+        set(p, value, "coalPieces", &self.coal_pieces)?;
+        set(p, value, "goldPieces", &self.gold_pieces)?;
+        set(p, value, "iridiumPieces", &self.iridium_pieces)?;
+        set(p, value, "quartzPieces", &self.quartz_pieces)?;
+        if let Some(game_version) = &self.game_version {
+            set(p, value, "gameVersion", game_version)?;
+        }
+        set(p, value, "caveChoice", &self.cave_choice)?;
+        set(p, value, "feed", &self.feed)?;
+        set(p, value, "farmingLevel", &self.farming_level)?;
+        set(p, value, "miningLevel", &self.mining_level)?;
+        set(p, value, "combatLevel", &self.combat_level)?;
+        set(p, value, "foragingLevel", &self.foraging_level)?;
+        set(p, value, "fishingLevel", &self.fishing_level)?;
+        set(p, value, "luckLevel", &self.luck_level)?;
+        set(
+            p,
+            value,
+            "newSkillPointsToSpend",
+            &self.new_skill_points_to_spend,
+        )?;
+        set(p, value, "addedFarmingLevel", &self.added_farming_level)?;
+        set(p, value, "addedMiningLevel", &self.added_mining_level)?;
+        set(p, value, "addedCombatLevel", &self.added_combat_level)?;
+        set(p, value, "addedForagingLevel", &self.added_foraging_level)?;
+        set(p, value, "addedFishingLevel", &self.added_fishing_level)?;
+        set(p, value, "addedLuckLevel", &self.added_luck_level)?;
+        set(p, value, "maxStamina", &self.max_stamina)?;
+        set(p, value, "maxItems", &self.max_items)?;
+        set(p, value, "lastSeenMovieWeek", &self.last_seen_movie_week)?;
+        set(p, value, "resilience", &self.resilience)?;
+        set(p, value, "attack", &self.attack)?;
+        set(p, value, "immunity", &self.immunity)?;
+        set(
+            p,
+            value,
+            "attackIncreaseModifier",
+            &self.attack_increase_modifier,
+        )?;
+        set(p, value, "knockbackModifier", &self.knockback_modifier)?;
+        set(p, value, "weaponSpeedModifier", &self.weapon_speed_modifier)?;
+        set(p, value, "critChanceModifier", &self.crit_chance_modifier)?;
+        set(p, value, "critPowerModifier", &self.crit_power_modifier)?;
+        set(
+            p,
+            value,
+            "weaponPrecisionModifier",
+            &self.weapon_precision_modifier,
+        )?;
+        set(p, value, "clubCoins", &self.club_coins)?;
+        set(p, value, "trashCanLevel", &self.trash_can_level)?;
+        set_optional(p, value, "toolBeingUpgraded", &self.tool_being_upgraded)?;
+        set(
+            p,
+            value,
+            "daysLeftForToolUpgrade",
+            &self.days_left_for_tool_upgrade,
+        )?;
+        set(p, value, "houseUpgradeLevel", &self.house_upgrade_level)?;
+        set(
+            p,
+            value,
+            "daysUntilHouseUpgrade",
+            &self.days_until_house_upgrade,
+        )?;
+        set(p, value, "coopUpgradeLevel", &self.coop_upgrade_level)?;
+        set(p, value, "barnUpgradeLevel", &self.barn_upgrade_level)?;
+        set(p, value, "hasGreenhouse", &self.has_greenhouse)?;
+        set(
+            p,
+            value,
+            "hasUnlockedSkullDoor",
+            &self.has_unlocked_skull_door,
+        )?;
+        if let Some(has_dark_talisman) = &self.has_dark_talisman {
+            set(p, value, "hasDarkTalisman", has_dark_talisman)?;
+        }
+        if let Some(has_magic_ink) = &self.has_magic_ink {
+            set(p, value, "hasMagicInk", has_magic_ink)?;
+        }
+        set(
+            p,
+            value,
+            "showChestColorPicker",
+            &self.show_chest_color_picker,
+        )?;
+        if let Some(has_magnifying_glass) = &self.has_magnifying_glass {
+            set(p, value, "hasMagnifyingGlass", has_magnifying_glass)?;
+        }
+        since(GameVersion::V1_5, || {
+            set(
+                p,
+                value,
+                "hasWateringCanEnchantment",
+                &self.has_watering_can_enchantment,
+            )
+        })?;
+        set(p, value, "magneticRadius", &self.magnetic_radius)?;
+        set(
+            p,
+            value,
+            "temporaryInvincibilityTimer",
+            &self.temporary_invincibility_timer,
+        )?;
+        set(p, value, "health", &self.health)?;
+        set(p, value, "maxHealth", &self.max_health)?;
+        set(p, value, "difficultyModifier", &self.difficulty_modifier)?;
+        set(p, value, "isMale", &self.is_male)?;
+        set(p, value, "hasBusTicket", &self.has_bus_ticket)?;
+        set(p, value, "stardewHero", &self.stardew_hero)?;
+        if let Some(has_club_card) = &self.has_club_card {
+            set(p, value, "hasClubCard", has_club_card)?;
+        }
+        if let Some(has_special_charm) = &self.has_special_charm {
+            set(p, value, "hasSpecialCharm", has_special_charm)?;
+        }
+        set_dict(
+            p,
+            value,
+            "basicShipped",
+            (id_tag(), "int"),
+            &self.basic_shipped,
+        )?;
+        set_dict(
+            p,
+            value,
+            "mineralsFound",
+            (id_tag(), "int"),
+            &self.minerals_found,
+        )?;
+        set_dict(
+            p,
+            value,
+            "recipesCooked",
+            (id_tag(), "int"),
+            &self.recipes_cooked,
+        )?;
+        set_dict(
+            p,
+            value,
+            "fishCaught",
+            (id_tag(), "ArrayOfInt"),
+            &self.fish_caught,
+        )?;
+        set_dict(
+            p,
+            value,
+            "archaeologyFound",
+            (id_tag(), "ArrayOfInt"),
+            &self.archaelogy_found,
+        )?;
+        set_dict(
+            p,
+            value,
+            "giftedItems",
+            ("string", "dictionary"),
+            &self.gifted_items,
+        )?;
+        set_dict(
+            p,
+            value,
+            "tailoredItems",
+            ("string", "int"),
+            &self.tailored_items,
+        )?;
+        set_dict(
+            p,
+            value,
+            "friendshipData",
+            ("string", "Friendship"),
+            &self.friendship_data,
+        )?;
+        set(
+            p,
+            value,
+            "dayOfMonthForSaveGame",
+            &self.day_of_month_for_save_game,
+        )?;
+        set(p, value, "seasonForSaveGame", &self.season_for_save_game)?;
+        set(p, value, "yearForSaveGame", &self.year_for_safe_game)?;
+        set(p, value, "overallsColor", &self.overalls_color)?;
+        set(p, value, "shirtColor", &self.shirt_color)?;
+        set(p, value, "skinColor", &self.skin_color)?;
+        set(p, value, "hairColor", &self.hair_color)?;
+        set(p, value, "eyeColor", &self.eye_color)?;
+        set(p, value, "saveTime", &self.save_time)?;
+        set(p, value, "isCustomized", &self.is_customized)?;
+        set(p, value, "homeLocation", &self.home_location)?;
+        set(p, value, "daysMarried", &self.days_married)?;
+        set(p, value, "movementMultiplier", &self.movement_multiplier)?;
+        set(p, value, "theaterBuildDate", &self.theater_build_date)?;
+        set(p, value, "deepestMineLevel", &self.deepest_mine_level)?;
+        set(p, value, "stamina", &self.stamina)?;
+        set(p, value, "totalMoneyEarned", &self.total_money_earned)?;
+        set(p, value, "millisecondsPlayed", &self.milliseconds_played)?;
+        if let Some(has_rusty_key) = &self.has_rusty_key {
+            set(p, value, "hasRustyKey", has_rusty_key)?;
+        }
+        if let Some(has_skull_key) = &self.has_skull_key {
+            set(p, value, "hasSkullKey", has_skull_key)?;
+        }
+        if let Some(can_understand_dwarves) = &self.can_understand_dwarves {
+            set(p, value, "canUnderstandDwarves", can_understand_dwarves)?;
+        }
+        set(p, value, "useSeparateWallets", &self.use_separate_wallets)?;
+        set(
+            p,
+            value,
+            "timesReachedMineBottom",
+            &self.times_reached_mine_bottom,
+        )?;
+        set(p, value, "UniqueMultiplayerID", &self.unique_multiplayer_id)?;
+        set(p, value, "money", &self.money)?;
+
+        Ok(())
+    }
+}
+
+fn get_id_list<T>(value: Node<'_, '_>, name: &str) -> Result<Vec<T>>
+where
+    T: FromStr<Err = Infallible>,
+{
+    get_list(value, name, id_tag(), str::parse)
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    pub seeds_sown: u32,
+    pub items_shipped: u32,
+    pub items_cooked: u32,
+    pub items_crafted: u32,
+    pub chicken_eggs_layed: u32,
+    pub duck_eggs_layed: u32,
+    pub cow_milk_produced: u32,
+    pub goat_milk_produced: u32,
+    pub rabbit_wool_produced: u32,
+    pub sheep_wool_produced: u32,
+    pub cheese_made: u32,
+    pub goat_cheese_made: u32,
+    pub truffles_found: u32,
+    pub stone_gathered: u32,
+    pub rocks_crushed: u32,
+    pub dirt_hoed: u32,
+    pub gifts_given: u32,
+    pub times_unconscious: u32,
+    pub average_bedtime: u32,
+    pub times_fished: u32,
+    pub fish_caught: u32,
+    pub boulders_cracked: u32,
+    pub stumps_chopped: u32,
+    pub steps_taken: u32,
+    pub monsters_killed: u32,
+    pub diamonds_found: u32,
+    pub prismatic_shards_found: u32,
+    pub other_precious_gems_found: u32,
+    pub cave_carrots_found: u32,
+    pub copper_found: u32,
+    pub iron_found: u32,
+    pub coal_found: u32,
+    pub coins_found: u32,
+    pub gold_found: u32,
+    pub iridium_found: u32,
+    pub bars_smelted: u32,
+    pub beverages_made: u32,
+    pub preserves_made: u32,
+    pub pieces_of_trash_recycled: u32,
+    pub mystic_stones_crushed: u32,
+    pub days_played: u32,
+    pub weeds_eliminated: u32,
+    pub sticks_chopped: u32,
+    pub notes_found: u32,
+    pub quests_completed: u32,
+    pub star_level_crops_shipped: u32,
+    pub crops_shipped: u32,
+    pub items_foraged: u32,
+    pub slimes_killed: u32,
+    pub geodes_cracked: u32,
+    pub good_friends: u32,
+    pub total_money_gifted: u32,
+    pub individual_money_earned: u32,
+    pub specific_monsters_killed: BTreeMap<String, u32>,
+    pub stat_dictionary: BTreeMap<String, u32>,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for Stats {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        // Since 1.6 the counters are no longer separate elements but all kept in a dictionary.
+        let values: Option<BTreeMap<String, u32>> =
+            since(GameVersion::V1_6, || get_dict(value, "Values"))?;
+        let stat = |name: &str| match &values {
+            Some(values) => Ok(values.get(name).copied().unwrap_or_default()),
+            None => parse(value, name),
+        };
+
+        Ok(Self {
+            seeds_sown: stat("seedsSown")?,
+            items_shipped: stat("itemsShipped")?,
+            items_cooked: stat("itemsCooked")?,
+            items_crafted: stat("itemsCrafted")?,
+            chicken_eggs_layed: stat("chickenEggsLayed")?,
+            duck_eggs_layed: stat("duckEggsLayed")?,
+            cow_milk_produced: stat("cowMilkProduced")?,
+            goat_milk_produced: stat("goatMilkProduced")?,
+            rabbit_wool_produced: stat("rabbitWoolProduced")?,
+            sheep_wool_produced: stat("sheepWoolProduced")?,
+            cheese_made: stat("cheeseMade")?,
+            goat_cheese_made: stat("goatCheeseMade")?,
+            truffles_found: stat("trufflesFound")?,
+            stone_gathered: stat("stoneGathered")?,
+            rocks_crushed: stat("rocksCrushed")?,
+            dirt_hoed: stat("dirtHoed")?,
+            gifts_given: stat("giftsGiven")?,
+            times_unconscious: stat("timesUnconscious")?,
+            average_bedtime: stat("averageBedtime")?,
+            times_fished: stat("timesFished")?,
+            fish_caught: stat("fishCaught")?,
+            boulders_cracked: stat("bouldersCracked")?,
+            stumps_chopped: stat("stumpsChopped")?,
+            steps_taken: stat("stepsTaken")?,
+            monsters_killed: stat("monstersKilled")?,
+            diamonds_found: stat("diamondsFound")?,
+            prismatic_shards_found: stat("prismaticShardsFound")?,
+            other_precious_gems_found: stat("otherPreciousGemsFound")?,
+            cave_carrots_found: stat("caveCarrotsFound")?,
+            copper_found: stat("copperFound")?,
+            iron_found: stat("ironFound")?,
+            coal_found: stat("coalFound")?,
+            coins_found: stat("coinsFound")?,
+            gold_found: stat("goldFound")?,
+            iridium_found: stat("iridiumFound")?,
+            bars_smelted: stat("barsSmelted")?,
+            beverages_made: stat("beveragesMade")?,
+            preserves_made: stat("preservesMade")?,
+            pieces_of_trash_recycled: stat("piecesOfTrashRecycled")?,
+            mystic_stones_crushed: stat("mysticStonesCrushed")?,
+            days_played: stat("daysPlayed")?,
+            weeds_eliminated: stat("weedsEliminated")?,
+            sticks_chopped: stat("sticksChopped")?,
+            notes_found: stat("notesFound")?,
+            quests_completed: stat("questsCompleted")?,
+            star_level_crops_shipped: stat("starLevelCropsShipped")?,
+            crops_shipped: stat("cropsShipped")?,
+            items_foraged: stat("itemsForaged")?,
+            slimes_killed: stat("slimesKilled")?,
+            geodes_cracked: stat("geodesCracked")?,
+            good_friends: stat("goodFriends")?,
+            total_money_gifted: stat("totalMoneyGifted")?,
+            individual_money_earned: stat("individualMoneyEarned")?,
+            specific_monsters_killed: get_dict(value, "specificMonstersKilled")?,
+            stat_dictionary: match values {
+                Some(values) => values,
+                None if get_optional(value, "stat_dictionary").is_some() => {
+                    get_dict(value, "stat_dictionary")?
+                }
+                None => BTreeMap::new(),
+            },
+        })
+    }
+}
+
+impl Emit for Stats {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        let stats = [
+            ("seedsSown", self.seeds_sown),
+            ("itemsShipped", self.items_shipped),
+            ("itemsCooked", self.items_cooked),
+            ("itemsCrafted", self.items_crafted),
+            ("chickenEggsLayed", self.chicken_eggs_layed),
+            ("duckEggsLayed", self.duck_eggs_layed),
+            ("cowMilkProduced", self.cow_milk_produced),
+            ("goatMilkProduced", self.goat_milk_produced),
+            ("rabbitWoolProduced", self.rabbit_wool_produced),
+            ("sheepWoolProduced", self.sheep_wool_produced),
+            ("cheeseMade", self.cheese_made),
+            ("goatCheeseMade", self.goat_cheese_made),
+            ("trufflesFound", self.truffles_found),
+            ("stoneGathered", self.stone_gathered),
+            ("rocksCrushed", self.rocks_crushed),
+            ("dirtHoed", self.dirt_hoed),
+            ("giftsGiven", self.gifts_given),
+            ("timesUnconscious", self.times_unconscious),
+            ("averageBedtime", self.average_bedtime),
+            ("timesFished", self.times_fished),
+            ("fishCaught", self.fish_caught),
+            ("bouldersCracked", self.boulders_cracked),
+            ("stumpsChopped", self.stumps_chopped),
+            ("stepsTaken", self.steps_taken),
+            ("monstersKilled", self.monsters_killed),
+            ("diamondsFound", self.diamonds_found),
+            ("prismaticShardsFound", self.prismatic_shards_found),
+            ("otherPreciousGemsFound", self.other_precious_gems_found),
+            ("caveCarrotsFound", self.cave_carrots_found),
+            ("copperFound", self.copper_found),
+            ("ironFound", self.iron_found),
+            ("coalFound", self.coal_found),
+            ("coinsFound", self.coins_found),
+            ("goldFound", self.gold_found),
+            ("iridiumFound", self.iridium_found),
+            ("barsSmelted", self.bars_smelted),
+            ("beveragesMade", self.beverages_made),
+            ("preservesMade", self.preserves_made),
+            ("piecesOfTrashRecycled", self.pieces_of_trash_recycled),
+            ("mysticStonesCrushed", self.mystic_stones_crushed),
+            ("daysPlayed", self.days_played),
+            ("weedsEliminated", self.weeds_eliminated),
+            ("sticksChopped", self.sticks_chopped),
+            ("notesFound", self.notes_found),
+            ("questsCompleted", self.quests_completed),
+            ("starLevelCropsShipped", self.star_level_crops_shipped),
+            ("cropsShipped", self.crops_shipped),
+            ("itemsForaged", self.items_foraged),
+            ("slimesKilled", self.slimes_killed),
+            ("geodesCracked", self.geodes_cracked),
+            ("goodFriends", self.good_friends),
+            ("totalMoneyGifted", self.total_money_gifted),
+            ("individualMoneyEarned", self.individual_money_earned),
+        ];
+
+        if version() >= GameVersion::V1_6 {
+            // The game leaves counters that were never increased out of the dictionary.
+            let mut values = self.stat_dictionary.clone();
+            for (name, stat) in stats.iter() {
+                if *stat != 0 || values.contains_key(*name) {
+                    values.insert((*name).to_owned(), *stat);
+                }
+            }
+
+            set_dict(p, value, "Values", ("string", "unsignedInt"), &values)?;
+        } else {
+            for (name, stat) in stats.iter() {
+                set(p, value, name, stat)?;
+            }
+
+            if get_optional(value, "stat_dictionary").is_some() {
+                set_dict(
+                    p,
+                    value,
+                    "stat_dictionary",
+                    ("string", "unsignedInt"),
+                    &self.stat_dictionary,
+                )?;
+            }
+        }
+
+        set_dict(
+            p,
+            value,
+            "specificMonstersKilled",
+            ("string", "int"),
+            &self.specific_monsters_killed,
+        )
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FishCaught {
+    pub count: u32,
+    pub max_size: u32,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for FishCaught {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        let values = Vec::<u32>::from_element(value)?;
+        if values.len() < 2 {
+            return Err(Error::at(
+                value,
+                ErrorKind::TooFewValues {
+                    expected: 2,
+                    found: values.len(),
+                },
+            ));
+        }
+
+        Ok(Self {
+            count: values[0],
+            max_size: values[1],
+        })
+    }
+}
+
+impl Emit for FishCaught {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        let values = value
+            .children()
+            .filter(|c| c.is_element())
+            .collect::<Vec<_>>();
+        if values.len() < 2 {
+            return Err(Error::at(
+                value,
+                ErrorKind::TooFewValues {
+                    expected: 2,
+                    found: values.len(),
+                },
+            ));
+        }
+
+        self.count.patch(p, values[0])?;
+        self.max_size.patch(p, values[1])
+    }
+}
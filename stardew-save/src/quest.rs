@@ -0,0 +1,452 @@
+use std::{convert::TryFrom, mem::discriminant};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{
+        get, get_bool, get_int_list, get_optional, get_optional_string, get_string,
+        get_string_list, parse, try_into_optional, try_into_optional_list, xsi_type,
+    },
+    error::{Error, Result},
+    item::Item,
+    ser::{
+        set, set_optional, set_optional_list, set_optional_text, set_text_list, unsupported, Emit,
+        Patcher,
+    },
+};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quest {
+    pub current_objective: String,
+    pub quest_description: String,
+    pub quest_title: String,
+    pub accepted: bool,
+    pub completed: bool,
+    pub daily_quest: bool,
+    pub show_new: bool,
+    pub can_be_cancelled: bool,
+    pub destroy: bool,
+    pub id: u64,
+    pub money_reward: u64,
+    pub quest_type: u64,
+    pub days_left: u8,
+    pub day_quest_accepted: i64,
+    pub next_quests: Vec<u64>,
+    pub ty: Option<QuestType>,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for Quest {
+    type Error = Error;
+
+    fn try_from((ty, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            current_objective: get_optional_string(value, "currentObjective").unwrap_or_default(),
+            quest_description: get_string(value, "questDescription")?,
+            quest_title: get_string(value, "questTitle")?,
+            accepted: get_bool(value, "accepted")?,
+            completed: get_bool(value, "completed")?,
+            daily_quest: get_bool(value, "dailyQuest")?,
+            show_new: get_bool(value, "showNew")?,
+            can_be_cancelled: get_bool(value, "canBeCancelled")?,
+            destroy: get_bool(value, "destroy")?,
+            id: parse(value, "id")?,
+            money_reward: parse(value, "moneyReward")?,
+            quest_type: parse(value, "questType")?,
+            days_left: parse(value, "daysLeft")?,
+            day_quest_accepted: parse(value, "dayQuestAccepted")?,
+            next_quests: get_int_list(value, "nextQuests")?,
+            ty: ty.map(|ty| QuestType::try_from((ty, value))).transpose()?,
+        })
+    }
+}
+
+impl Emit for Quest {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        let original = xsi_type(value)
+            .map(|ty| QuestType::try_from((ty, value)))
+            .transpose()?;
+        let same_type = match (&original, &self.ty) {
+            (Some(original), Some(ty)) => original.same_type(ty),
+            (original, ty) => original.is_none() && ty.is_none(),
+        };
+        if !same_type {
+            return Err(unsupported(value, "a different type of quest"));
+        }
+
+        set_optional_text(
+            p,
+            value,
+            "currentObjective",
+            Some(self.current_objective.as_str()).filter(|objective| !objective.is_empty()),
+        )?;
+        set(p, value, "questDescription", &self.quest_description)?;
+        set(p, value, "questTitle", &self.quest_title)?;
+        set(p, value, "accepted", &self.accepted)?;
+        set(p, value, "completed", &self.completed)?;
+        set(p, value, "dailyQuest", &self.daily_quest)?;
+        set(p, value, "showNew", &self.show_new)?;
+        set(p, value, "canBeCancelled", &self.can_be_cancelled)?;
+        set(p, value, "destroy", &self.destroy)?;
+        set(p, value, "id", &self.id)?;
+        set(p, value, "moneyReward", &self.money_reward)?;
+        set(p, value, "questType", &self.quest_type)?;
+        set(p, value, "daysLeft", &self.days_left)?;
+        set(p, value, "dayQuestAccepted", &self.day_quest_accepted)?;
+        set_text_list(p, value, "nextQuests", "int", &self.next_quests)?;
+
+        match &self.ty {
+            Some(ty) => ty.emit(p, value),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuestType {
+    ItemDeliveryQuest {
+        target_message: Option<String>,
+        target: String,
+        item: i64,
+        number: u32,
+        delivery_item: Option<Item>,
+        parts: Vec<DescriptionElement>,
+        dialogueparts: Vec<DescriptionElement>,
+        objective: Option<DescriptionElement>,
+    },
+    LostItemQuest {
+        npc_name: String,
+        location_of_item: String,
+        item_index: i64,
+        tile_x: i32,
+        tile_y: i32,
+        item_found: bool,
+        objective: Option<DescriptionElement>,
+    },
+    SlayMonsterQuest {
+        monster_name: String,
+        target_message: Option<String>,
+        target: String,
+        number_to_kill: u32,
+        reward: u64,
+        number_killed: u32,
+        parts: Vec<DescriptionElement>,
+        dialogueparts: Vec<DescriptionElement>,
+        objective: Option<DescriptionElement>,
+    },
+    FishingQuest {
+        target: String,
+        which_fish: i64,
+        fish: Option<Item>,
+        number_to_fish: u32,
+        reward: u64,
+        number_fished: u32,
+        parts: Vec<DescriptionElement>,
+        dialogueparts: Vec<DescriptionElement>,
+        objective: Option<DescriptionElement>,
+    },
+    ResourceCollectionQuest {
+        target_message: Option<String>,
+        target: String,
+        resource: i64,
+        delivery_item: Option<Item>,
+        number: u32,
+        number_collected: u32,
+        reward: u64,
+        parts: Vec<DescriptionElement>,
+        dialogueparts: Vec<DescriptionElement>,
+        objective: Option<DescriptionElement>,
+    },
+    SocializeQuest {
+        who_to_greet: Vec<String>,
+        total: u32,
+        parts: Vec<DescriptionElement>,
+        objective: Option<DescriptionElement>,
+    },
+    CraftingQuest {
+        is_big_craftable: bool,
+        index_to_craft: i64,
+    },
+    GoSomewhereQuest {
+        where_to_go: String,
+    },
+    Other(String),
+}
+
+impl TryFrom<(&str, Node<'_, '_>)> for QuestType {
+    type Error = Error;
+
+    fn try_from((ty, value): (&str, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(match ty {
+            "ItemDeliveryQuest" => Self::ItemDeliveryQuest {
+                target_message: get_optional_string(value, "targetMessage"),
+                target: get_string(value, "target")?,
+                item: parse(value, "item")?,
+                number: parse(value, "number")?,
+                delivery_item: try_into_optional(value, "deliveryItem")?,
+                parts: try_into_optional_list(value, "parts", "DescriptionElement")?,
+                dialogueparts: try_into_optional_list(
+                    value,
+                    "dialogueparts",
+                    "DescriptionElement",
+                )?,
+                objective: try_into_optional(value, "objective")?,
+            },
+            "LostItemQuest" => Self::LostItemQuest {
+                npc_name: get_string(value, "npcName")?,
+                location_of_item: get_string(value, "locationOfItem")?,
+                item_index: parse(value, "itemIndex")?,
+                tile_x: parse(value, "tileX")?,
+                tile_y: parse(value, "tileY")?,
+                item_found: get_bool(value, "itemFound")?,
+                objective: try_into_optional(value, "objective")?,
+            },
+            "SlayMonsterQuest" => Self::SlayMonsterQuest {
+                monster_name: get_string(value, "monsterName")?,
+                target_message: get_optional_string(value, "targetMessage"),
+                target: get_string(value, "target")?,
+                number_to_kill: parse(value, "numberToKill")?,
+                reward: parse(value, "reward")?,
+                number_killed: parse(value, "numberKilled")?,
+                parts: try_into_optional_list(value, "parts", "DescriptionElement")?,
+                dialogueparts: try_into_optional_list(
+                    value,
+                    "dialogueparts",
+                    "DescriptionElement",
+                )?,
+                objective: try_into_optional(value, "objective")?,
+            },
+            "FishingQuest" => Self::FishingQuest {
+                target: get_string(value, "target")?,
+                which_fish: parse(value, "whichFish")?,
+                fish: try_into_optional(value, "fish")?,
+                number_to_fish: parse(value, "numberToFish")?,
+                reward: parse(value, "reward")?,
+                number_fished: parse(value, "numberFished")?,
+                parts: try_into_optional_list(value, "parts", "DescriptionElement")?,
+                dialogueparts: try_into_optional_list(
+                    value,
+                    "dialogueparts",
+                    "DescriptionElement",
+                )?,
+                objective: try_into_optional(value, "objective")?,
+            },
+            "ResourceCollectionQuest" => Self::ResourceCollectionQuest {
+                target_message: get_optional_string(value, "targetMessage"),
+                target: get_string(value, "target")?,
+                resource: parse(value, "resource")?,
+                delivery_item: try_into_optional(value, "deliveryItem")?,
+                number: parse(value, "number")?,
+                number_collected: parse(value, "numberCollected")?,
+                reward: parse(value, "reward")?,
+                parts: try_into_optional_list(value, "parts", "DescriptionElement")?,
+                dialogueparts: try_into_optional_list(
+                    value,
+                    "dialogueparts",
+                    "DescriptionElement",
+                )?,
+                objective: try_into_optional(value, "objective")?,
+            },
+            "SocializeQuest" => Self::SocializeQuest {
+                who_to_greet: get_string_list(value, "whoToGreet")?,
+                total: parse(value, "total")?,
+                parts: try_into_optional_list(value, "parts", "DescriptionElement")?,
+                objective: try_into_optional(value, "objective")?,
+            },
+            "CraftingQuest" => Self::CraftingQuest {
+                is_big_craftable: get_bool(value, "isBigCraftable")?,
+                index_to_craft: parse(value, "indexToCraft")?,
+            },
+            "GoSomewhereQuest" => Self::GoSomewhereQuest {
+                where_to_go: get_string(value, "whereToGo")?,
+            },
+            _ => Self::Other(ty.to_owned()),
+        })
+    }
+}
+
+impl QuestType {
+    fn same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Other(ty), Self::Other(other)) => ty == other,
+            (ty, other) => discriminant(ty) == discriminant(other),
+        }
+    }
+}
+
+impl Emit for QuestType {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        match self {
+            Self::ItemDeliveryQuest {
+                target_message,
+                target,
+                item,
+                number,
+                delivery_item,
+                parts,
+                dialogueparts,
+                objective,
+            } => {
+                set_optional_text(p, value, "targetMessage", target_message.as_deref())?;
+                set(p, value, "target", target)?;
+                set(p, value, "item", item)?;
+                set(p, value, "number", number)?;
+                set_optional(p, value, "deliveryItem", delivery_item)?;
+                set_optional_list(p, value, "parts", parts)?;
+                set_optional_list(p, value, "dialogueparts", dialogueparts)?;
+                set_optional(p, value, "objective", objective)?;
+            }
+            Self::LostItemQuest {
+                npc_name,
+                location_of_item,
+                item_index,
+                tile_x,
+                tile_y,
+                item_found,
+                objective,
+            } => {
+                set(p, value, "npcName", npc_name)?;
+                set(p, value, "locationOfItem", location_of_item)?;
+                set(p, value, "itemIndex", item_index)?;
+                set(p, value, "tileX", tile_x)?;
+                set(p, value, "tileY", tile_y)?;
+                set(p, value, "itemFound", item_found)?;
+                set_optional(p, value, "objective", objective)?;
+            }
+            Self::SlayMonsterQuest {
+                monster_name,
+                target_message,
+                target,
+                number_to_kill,
+                reward,
+                number_killed,
+                parts,
+                dialogueparts,
+                objective,
+            } => {
+                set(p, value, "monsterName", monster_name)?;
+                set_optional_text(p, value, "targetMessage", target_message.as_deref())?;
+                set(p, value, "target", target)?;
+                set(p, value, "numberToKill", number_to_kill)?;
+                set(p, value, "reward", reward)?;
+                set(p, value, "numberKilled", number_killed)?;
+                set_optional_list(p, value, "parts", parts)?;
+                set_optional_list(p, value, "dialogueparts", dialogueparts)?;
+                set_optional(p, value, "objective", objective)?;
+            }
+            Self::FishingQuest {
+                target,
+                which_fish,
+                fish,
+                number_to_fish,
+                reward,
+                number_fished,
+                parts,
+                dialogueparts,
+                objective,
+            } => {
+                set(p, value, "target", target)?;
+                set(p, value, "whichFish", which_fish)?;
+                set_optional(p, value, "fish", fish)?;
+                set(p, value, "numberToFish", number_to_fish)?;
+                set(p, value, "reward", reward)?;
+                set(p, value, "numberFished", number_fished)?;
+                set_optional_list(p, value, "parts", parts)?;
+                set_optional_list(p, value, "dialogueparts", dialogueparts)?;
+                set_optional(p, value, "objective", objective)?;
+            }
+            Self::ResourceCollectionQuest {
+                target_message,
+                target,
+                resource,
+                delivery_item,
+                number,
+                number_collected,
+                reward,
+                parts,
+                dialogueparts,
+                objective,
+            } => {
+                set_optional_text(p, value, "targetMessage", target_message.as_deref())?;
+                set(p, value, "target", target)?;
+                set(p, value, "resource", resource)?;
+                set_optional(p, value, "deliveryItem", delivery_item)?;
+                set(p, value, "number", number)?;
+                set(p, value, "numberCollected", number_collected)?;
+                set(p, value, "reward", reward)?;
+                set_optional_list(p, value, "parts", parts)?;
+                set_optional_list(p, value, "dialogueparts", dialogueparts)?;
+                set_optional(p, value, "objective", objective)?;
+            }
+            Self::SocializeQuest {
+                who_to_greet,
+                total,
+                parts,
+                objective,
+            } => {
+                set_text_list(p, value, "whoToGreet", "string", who_to_greet)?;
+                set(p, value, "total", total)?;
+                set_optional_list(p, value, "parts", parts)?;
+                set_optional(p, value, "objective", objective)?;
+            }
+            Self::CraftingQuest {
+                is_big_craftable,
+                index_to_craft,
+            } => {
+                set(p, value, "isBigCraftable", is_big_craftable)?;
+                set(p, value, "indexToCraft", index_to_craft)?;
+            }
+            Self::GoSomewhereQuest { where_to_go } => set(p, value, "whereToGo", where_to_go)?,
+            Self::Other(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DescriptionElement {
+    pub xml_key: String,
+    pub params: Vec<String>,
+}
+
+impl TryFrom<(Option<&str>, Node<'_, '_>)> for DescriptionElement {
+    type Error = Error;
+
+    fn try_from((_, value): (Option<&str>, Node<'_, '_>)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            xml_key: get_optional_string(value, "xmlKey").unwrap_or_default(),
+            params: get_optional(value, "param")
+                .map(|param| {
+                    param
+                        .children()
+                        .filter(|c| c.is_element())
+                        .filter_map(|c| c.text().or_else(|| get(c, "xmlKey").ok()?.text()))
+                        .map(ToOwned::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Parameters are either plain text or nested description elements, which are only read, so they
+/// can't be changed.
+impl Emit for DescriptionElement {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set_optional_text(
+            p,
+            value,
+            "xmlKey",
+            Some(self.xml_key.as_str()).filter(|key| !key.is_empty()),
+        )?;
+
+        if Self::try_from((None, value))?.params != self.params {
+            return Err(unsupported(value, "changed description parameters"));
+        }
+
+        Ok(())
+    }
+}
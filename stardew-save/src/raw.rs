@@ -0,0 +1,35 @@
+use std::convert::TryFrom;
+
+use roxmltree::Node;
+
+use crate::error::{Error, Result};
+
+/// An element that isn't modeled yet, kept as the exact XML the game wrote so it can be written
+/// back without losing anything.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RawXml(pub String);
+
+impl RawXml {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for RawXml {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self(
+            value.document().input_text()[value.range()].to_owned(),
+        ))
+    }
+}
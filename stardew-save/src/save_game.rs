@@ -0,0 +1,407 @@
+use std::{
+    collections::BTreeMap,
+    convert::{TryFrom, TryInto},
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{
+        collect_recoverable, get_bool, get_dict, get_int_list, get_optional, get_raw, get_string,
+        get_string_list, is_nil, parse, recover, try_into, try_into_list, xsi_type,
+    },
+    error::{Error, Result, UnknownVariant},
+    friendship::{FarmerPair, Friendship},
+    location::{GameLocation, Position},
+    player::Player,
+    raw::RawXml,
+    ser::{set, set_dict, set_list, set_raw, set_text_list, unsupported, Emit, Patcher},
+    version::{since, GameVersion, VersionGuard},
+};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SaveGame {
+    pub player: Player,
+    pub farmhands: Vec<Player>,
+    pub locations: Vec<GameLocation>,
+    pub current_season: Season,
+    pub sam_band_name: String,
+    pub elliott_book_name: String,
+    pub broadcasted_mail: RawXml,
+    pub world_state_ids: Vec<String>,
+    pub lost_books_found: u64,
+    pub day_of_month: u8,
+    pub year: u32,
+    pub farmer_wallpaper: u64,
+    pub farmer_floor: u64,
+    pub current_wallpaper: u64,
+    pub current_floor: u64,
+    pub current_song_index: u64,
+    pub countdown_to_wedding: RawXml,
+    pub incubating_egg: Position,
+    pub chance_to_rain_tomorrow: f64,
+    pub daily_luck: f64,
+    pub unique_id_for_this_game: String,
+    pub wedding_today: bool,
+    pub is_raining: bool,
+    pub is_debris_weather: bool,
+    pub shipping_tax: bool,
+    pub bloom_day: bool,
+    pub is_lightning: bool,
+    pub is_snowing: bool,
+    pub should_spawn_monsters: bool,
+    pub has_applied_1_3_update_changes: Option<bool>,
+    pub has_applied_1_4_update_changes: Option<bool>,
+    pub music_volume: f64,
+    pub sound_volume: f64,
+    pub crops_of_the_week: Vec<u64>,
+    pub dis_of_the_day: RawXml,
+    pub highest_player_limit: u8,
+    pub move_building_permission_mode: u64,
+    pub banned_users: RawXml,
+    /// Bundles of the Community Center by `<room>/<id>`, which are only stored in the save since
+    /// they can be remixed.
+    pub bundle_data: BTreeMap<String, String>,
+    pub latest_id: i64,
+    pub custom_data: RawXml,
+    pub mine_permanent_mine_changes: RawXml,
+    pub mine_lowest_level_reached: u8,
+    pub minecart_high_score: u64,
+    pub weather_for_tomorrow: u64,
+    pub which_farm: u8,
+    pub junimo_cart_leaderboards: RawXml,
+    pub farmer_friendships: Vec<(FarmerPair, Friendship)>,
+    pub cellar_assignments: RawXml,
+    pub last_applied_save_fix: Option<u64>,
+    pub island: Option<IslandProgress>,
+    pub game_version: GameVersion,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for SaveGame {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        let game_version = recover(GameVersion::detect(value))?;
+        let _version = VersionGuard::new(game_version);
+
+        Ok(Self {
+            player: try_into(value, "player")?,
+            farmhands: get_farmhands(value)?,
+            locations: try_into_list(value, "locations", "GameLocation")?,
+            current_season: parse(value, "currentSeason")?,
+            sam_band_name: get_string(value, "samBandName")?,
+            elliott_book_name: get_string(value, "elliottBookName")?,
+            broadcasted_mail: get_raw(value, "broadcastedMail")?,
+            world_state_ids: get_string_list(value, "worldStateIDs")?,
+            lost_books_found: parse(value, "lostBooksFound")?,
+            day_of_month: parse(value, "dayOfMonth")?,
+            year: parse(value, "year")?,
+            farmer_wallpaper: parse(value, "farmerWallpaper")?,
+            farmer_floor: parse(value, "FarmerFloor")?,
+            current_wallpaper: parse(value, "currentWallpaper")?,
+            current_floor: parse(value, "currentFloor")?,
+            current_song_index: parse(value, "currentSongIndex")?,
+            countdown_to_wedding: get_raw(value, "countdownToWedding")?,
+            incubating_egg: try_into(value, "incubatingEgg")?,
+            chance_to_rain_tomorrow: parse(value, "chanceToRainTomorrow")?,
+            daily_luck: parse(value, "dailyLuck")?,
+            unique_id_for_this_game: get_string(value, "uniqueIDForThisGame")?,
+            wedding_today: get_bool(value, "weddingToday")?,
+            is_raining: get_bool(value, "isRaining")?,
+            is_debris_weather: get_bool(value, "isDebrisWeather")?,
+            shipping_tax: get_bool(value, "shippingTax")?,
+            bloom_day: get_bool(value, "bloomDay")?,
+            is_lightning: get_bool(value, "isLightning")?,
+            is_snowing: get_bool(value, "isSnowing")?,
+            should_spawn_monsters: get_bool(value, "shouldSpawnMonsters")?,
+            has_applied_1_3_update_changes: since(GameVersion::V1_3, || {
+                get_bool(value, "hasApplied1_3_UpdateChanges")
+            })?,
+            has_applied_1_4_update_changes: since(GameVersion::V1_4, || {
+                get_bool(value, "hasApplied1_4_UpdateChanges")
+            })?,
+            music_volume: parse(value, "musicVolume")?,
+            sound_volume: parse(value, "soundVolume")?,
+            crops_of_the_week: get_int_list(value, "cropsOfTheWeek")?,
+            dis_of_the_day: get_raw(value, "dishOfTheDay")?,
+            highest_player_limit: parse(value, "highestPlayerLimit")?,
+            move_building_permission_mode: parse(value, "moveBuildingPermissionMode")?,
+            banned_users: get_raw(value, "bannedUsers")?,
+            bundle_data: since(GameVersion::V1_5, || get_dict(value, "bundleData"))?
+                .unwrap_or_default(),
+            latest_id: parse(value, "latestID")?,
+            custom_data: get_raw(value, "customData")?,
+            mine_permanent_mine_changes: get_raw(value, "mine_permanentMineChanges")?,
+            mine_lowest_level_reached: parse(value, "mine_lowestLevelReached")?,
+            minecart_high_score: parse(value, "minecartHighScore")?,
+            weather_for_tomorrow: parse(value, "weatherForTomorrow")?,
+            which_farm: parse(value, "whichFarm")?,
+            junimo_cart_leaderboards: get_raw(value, "junimoKartLeaderboards")?,
+            farmer_friendships: since(GameVersion::V1_3, || get_dict(value, "farmerFriendships"))?
+                .unwrap_or_default(),
+            cellar_assignments: get_raw(value, "cellarAssignments")?,
+            last_applied_save_fix: since(GameVersion::V1_4, || parse(value, "lastAppliedSaveFix"))?,
+            island: since(GameVersion::V1_5, || value.try_into())?,
+            game_version,
+        })
+    }
+}
+
+impl Emit for SaveGame {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "player", &self.player)?;
+
+        let farmhands = farmhand_nodes(value);
+        if farmhands.len() != self.farmhands.len() {
+            return Err(unsupported(value, "a different number of farmhands"));
+        }
+        for (node, farmhand) in farmhands.into_iter().zip(&self.farmhands) {
+            farmhand.emit(p, node)?;
+        }
+
+        set_list(p, value, "locations", &self.locations)?;
+        set(p, value, "currentSeason", &self.current_season)?;
+        set(p, value, "samBandName", &self.sam_band_name)?;
+        set(p, value, "elliottBookName", &self.elliott_book_name)?;
+        set_raw(p, value, "broadcastedMail", &self.broadcasted_mail)?;
+        set_text_list(p, value, "worldStateIDs", "string", &self.world_state_ids)?;
+        set(p, value, "lostBooksFound", &self.lost_books_found)?;
+        set(p, value, "dayOfMonth", &self.day_of_month)?;
+        set(p, value, "year", &self.year)?;
+        set(p, value, "farmerWallpaper", &self.farmer_wallpaper)?;
+        set(p, value, "FarmerFloor", &self.farmer_floor)?;
+        set(p, value, "currentWallpaper", &self.current_wallpaper)?;
+        set(p, value, "currentFloor", &self.current_floor)?;
+        set(p, value, "currentSongIndex", &self.current_song_index)?;
+        set_raw(p, value, "countdownToWedding", &self.countdown_to_wedding)?;
+        set(p, value, "incubatingEgg", &self.incubating_egg)?;
+        set(
+            p,
+            value,
+            "chanceToRainTomorrow",
+            &self.chance_to_rain_tomorrow,
+        )?;
+        set(p, value, "dailyLuck", &self.daily_luck)?;
+        set(
+            p,
+            value,
+            "uniqueIDForThisGame",
+            &self.unique_id_for_this_game,
+        )?;
+        set(p, value, "weddingToday", &self.wedding_today)?;
+        set(p, value, "isRaining", &self.is_raining)?;
+        set(p, value, "isDebrisWeather", &self.is_debris_weather)?;
+        set(p, value, "shippingTax", &self.shipping_tax)?;
+        set(p, value, "bloomDay", &self.bloom_day)?;
+        set(p, value, "isLightning", &self.is_lightning)?;
+        set(p, value, "isSnowing", &self.is_snowing)?;
+        set(p, value, "shouldSpawnMonsters", &self.should_spawn_monsters)?;
+        set(p, value, "musicVolume", &self.music_volume)?;
+        set(p, value, "soundVolume", &self.sound_volume)?;
+        set_text_list(p, value, "cropsOfTheWeek", "int", &self.crops_of_the_week)?;
+        set_raw(p, value, "dishOfTheDay", &self.dis_of_the_day)?;
+        set(p, value, "highestPlayerLimit", &self.highest_player_limit)?;
+        set(
+            p,
+            value,
+            "moveBuildingPermissionMode",
+            &self.move_building_permission_mode,
+        )?;
+        set_raw(p, value, "bannedUsers", &self.banned_users)?;
+        since(GameVersion::V1_5, || {
+            set_dict(
+                p,
+                value,
+                "bundleData",
+                ("string", "string"),
+                &self.bundle_data,
+            )
+        })?;
+        set(p, value, "latestID", &self.latest_id)?;
+        set_raw(p, value, "customData", &self.custom_data)?;
+        set_raw(
+            p,
+            value,
+            "mine_permanentMineChanges",
+            &self.mine_permanent_mine_changes,
+        )?;
+        set(
+            p,
+            value,
+            "mine_lowestLevelReached",
+            &self.mine_lowest_level_reached,
+        )?;
+        set(p, value, "minecartHighScore", &self.minecart_high_score)?;
+        set(p, value, "weatherForTomorrow", &self.weather_for_tomorrow)?;
+        set(p, value, "whichFarm", &self.which_farm)?;
+        set_raw(
+            p,
+            value,
+            "junimoKartLeaderboards",
+            &self.junimo_cart_leaderboards,
+        )?;
+        set_raw(p, value, "cellarAssignments", &self.cellar_assignments)?;
+
+        if let Some(applied) = &self.has_applied_1_3_update_changes {
+            set(p, value, "hasApplied1_3_UpdateChanges", applied)?;
+        }
+        if let Some(applied) = &self.has_applied_1_4_update_changes {
+            set(p, value, "hasApplied1_4_UpdateChanges", applied)?;
+        }
+        since(GameVersion::V1_3, || {
+            set_dict(
+                p,
+                value,
+                "farmerFriendships",
+                ("FarmerPair", "Friendship"),
+                self.farmer_friendships.iter().map(|(k, v)| (k, v)),
+            )
+        })?;
+        if let Some(save_fix) = &self.last_applied_save_fix {
+            set(p, value, "lastAppliedSaveFix", save_fix)?;
+        }
+        if let Some(island) = &self.island {
+            island.emit(p, value)?;
+        }
+        since(GameVersion::V1_4, || {
+            set(p, value, "gameVersion", &self.game_version)
+        })?;
+
+        Ok(())
+    }
+}
+
+impl SaveGame {
+    /// All farmers of the save, starting with the host.
+    pub fn farmers(&self) -> impl Iterator<Item = &Player> {
+        std::iter::once(&self.player).chain(&self.farmhands)
+    }
+
+    pub fn farmers_mut(&mut self) -> impl Iterator<Item = &mut Player> {
+        std::iter::once(&mut self.player).chain(&mut self.farmhands)
+    }
+}
+
+/// Farmhands are kept in a list of their own since 1.6, while older versions stored each of
+/// them inside the cabin they live in.
+fn get_farmhands(value: Node<'_, '_>) -> Result<Vec<Player>> {
+    collect_recoverable(farmhand_nodes(value).into_iter().map(Player::try_from))
+}
+
+fn farmhand_nodes<'a>(value: Node<'a, 'a>) -> Vec<Node<'a, 'a>> {
+    let farmhands = match get_optional(value, "farmhands") {
+        Some(list) => list.children().filter(|c| c.is_element()).collect(),
+        None => get_optional(value, "locations")
+            .into_iter()
+            .flat_map(|locations| locations.children())
+            .filter_map(|location| get_optional(location, "buildings"))
+            .flat_map(|buildings| buildings.children())
+            .filter_map(|building| get_optional(building, "indoors"))
+            .filter(|indoors| xsi_type(*indoors) == Some("Cabin"))
+            .filter_map(|cabin| get_optional(cabin, "farmhand"))
+            .collect::<Vec<_>>(),
+    };
+
+    farmhands
+        .into_iter()
+        .filter(|farmhand| !is_nil(*farmhand) && is_customized(*farmhand))
+        .collect()
+}
+
+/// Cabins come with a blank farmhand that is only filled in once someone joins the farm and
+/// creates their character.
+fn is_customized(farmhand: Node<'_, '_>) -> bool {
+    let text = |name| get_optional(farmhand, name).and_then(|node| node.text());
+
+    text("isCustomized") == Some("true") && text("name").is_some_and(|name| !name.is_empty())
+}
+
+/// Ginger Island progress, which was added to the game in 1.5.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IslandProgress {
+    pub golden_walnuts: i32,
+    pub golden_walnuts_found: u32,
+    pub mini_shipping_bins_obtained: u32,
+    pub golden_coconut_cracked: bool,
+    pub parrot_platforms_unlocked: bool,
+}
+
+impl<'a> TryFrom<Node<'a, 'a>> for IslandProgress {
+    type Error = Error;
+
+    fn try_from(value: Node<'a, 'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            golden_walnuts: parse(value, "goldenWalnuts")?,
+            golden_walnuts_found: parse(value, "goldenWalnutsFound")?,
+            mini_shipping_bins_obtained: parse(value, "miniShippingBinsObtained")?,
+            golden_coconut_cracked: get_bool(value, "goldenCoconutCracked")?,
+            parrot_platforms_unlocked: get_bool(value, "parrotPlatformsUnlocked")?,
+        })
+    }
+}
+
+impl Emit for IslandProgress {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        set(p, value, "goldenWalnuts", &self.golden_walnuts)?;
+        set(p, value, "goldenWalnutsFound", &self.golden_walnuts_found)?;
+        set(
+            p,
+            value,
+            "miniShippingBinsObtained",
+            &self.mini_shipping_bins_obtained,
+        )?;
+        set(
+            p,
+            value,
+            "goldenCoconutCracked",
+            &self.golden_coconut_cracked,
+        )?;
+        set(
+            p,
+            value,
+            "parrotPlatformsUnlocked",
+            &self.parrot_platforms_unlocked,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Season {
+    #[default]
+    Spring,
+    Summer,
+    Fall,
+    Winter,
+}
+
+impl FromStr for Season {
+    type Err = UnknownVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "spring" => Self::Spring,
+            "summer" => Self::Summer,
+            "fall" => Self::Fall,
+            "winter" => Self::Winter,
+            _ => return Err(UnknownVariant::new("season", s)),
+        })
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Spring => "spring",
+            Self::Summer => "summer",
+            Self::Fall => "fall",
+            Self::Winter => "winter",
+        })
+    }
+}
@@ -0,0 +1,765 @@
+use std::{collections::BTreeMap, fmt::Display, ops::Range, str::FromStr};
+
+use roxmltree::{Document, Node};
+
+use crate::{
+    de::{get, get_optional, is_nil, FromElement},
+    error::{Error, ErrorKind, Result},
+    friendship::{FarmerPair, Friendship, FriendshipStatus, WorldDate},
+    item::{ClothingItem, Color, Item, ItemId, PreserveType, Quality},
+    location::Position,
+    player::{FishCaught, Player, Stats},
+    raw::RawXml,
+    save_game::Season,
+    version::GameVersion,
+};
+
+/// Collects changes to the original document, which are all applied at once when finished. Any
+/// part of the document that isn't patched is kept exactly as the game wrote it.
+pub(crate) struct Patcher<'a> {
+    doc: &'a Document<'a>,
+    source: &'a str,
+    patches: Vec<(Range<usize>, String)>,
+}
+
+impl<'a> Patcher<'a> {
+    pub(crate) fn new(doc: &'a Document<'a>) -> Self {
+        Self {
+            doc,
+            source: doc.input_text(),
+            patches: Vec::new(),
+        }
+    }
+
+    /// Apply all patches. Patches can't overlap, as there is no way to tell which of them should
+    /// win, so changing both an element and something inside of it is an error.
+    pub(crate) fn finish(mut self) -> Result<String> {
+        // Stable sort, so multiple insertions at the same position keep their order.
+        self.patches.sort_by_key(|(range, _)| range.start);
+
+        let mut output = String::with_capacity(self.source.len());
+        let mut pos = 0;
+
+        for (range, content) in &self.patches {
+            if range.start < pos {
+                return Err(unsupported(
+                    self.element_at(range.start),
+                    "overlapping changes to an element and its content",
+                ));
+            }
+
+            output.push_str(&self.source[pos..range.start]);
+            output.push_str(content);
+            pos = range.end;
+        }
+
+        output.push_str(&self.source[pos..]);
+        Ok(output)
+    }
+
+    /// Innermost element that contains the position, for error reporting.
+    fn element_at(&self, pos: usize) -> Node<'a, 'a> {
+        self.doc
+            .descendants()
+            .filter(|node| node.is_element() && node.range().contains(&pos))
+            .last()
+            .unwrap_or_else(|| self.doc.root_element())
+    }
+
+    /// An empty patcher for the same document, to find out what a value would change without
+    /// changing anything yet.
+    fn scratch(&self) -> Self {
+        Self {
+            doc: self.doc,
+            source: self.source,
+            patches: Vec::new(),
+        }
+    }
+
+    fn replace(&mut self, range: Range<usize>, content: String) {
+        self.patches.push((range, content));
+    }
+
+    /// Replace the content of an element, keeping its tag and attributes.
+    fn set_inner(&mut self, value: Node<'_, '_>, content: String) {
+        let range = value.range();
+        let raw = &self.source[range.clone()];
+        let start_tag_len = raw.find('>').map_or(raw.len(), |i| i + 1);
+
+        if raw[..start_tag_len].ends_with("/>") {
+            let name = raw[1..]
+                .split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                .next()
+                .unwrap_or_default();
+            let start_tag = raw[..start_tag_len - 2].trim_end();
+
+            self.replace(range, format!("{}>{}</{}>", start_tag, content, name));
+        } else {
+            let end_tag_start = raw.rfind("</").unwrap_or(raw.len());
+            self.replace(
+                range.start + start_tag_len..range.start + end_tag_start,
+                content,
+            );
+        }
+    }
+
+    /// Append new children to an element, following the indentation of the existing ones.
+    fn append(&mut self, value: Node<'_, '_>, children: &[String]) {
+        let (indent, end) = indentation(value);
+        let mut content = children
+            .iter()
+            .map(|child| format!("{}{}", indent, child))
+            .collect::<String>();
+        content.push_str(&end);
+
+        match value.last_child() {
+            Some(last) if last.is_element() => {
+                let range = value.range();
+                let raw = &self.source[range.clone()];
+                let end_tag_start = range.start + raw.rfind("</").unwrap_or(raw.len());
+                self.replace(end_tag_start..end_tag_start, content);
+            }
+            Some(last) => self.replace(last.range(), content),
+            None => self.set_inner(value, content),
+        }
+    }
+
+    /// Insert a new element in front of an existing one, with the same indentation.
+    fn insert_before(&mut self, value: Node<'_, '_>, child: &str) {
+        let indent = value
+            .prev_sibling()
+            .filter(|prev| prev.is_text())
+            .and_then(|prev| prev.text())
+            .filter(|text| text.trim().is_empty())
+            .unwrap_or_default();
+        let start = value.range().start;
+
+        self.replace(start..start, format!("{}{}", child, indent));
+    }
+
+    /// Remove an element together with the indentation in front of it.
+    fn remove(&mut self, value: Node<'_, '_>) {
+        let start = value
+            .prev_sibling()
+            .filter(|prev| prev.is_text() && prev.text().unwrap_or_default().trim().is_empty())
+            .map_or(value.range().start, |prev| prev.range().start);
+
+        self.replace(start..value.range().end, String::new());
+    }
+
+    fn set_nil(&mut self, value: Node<'_, '_>) {
+        self.replace(
+            value.range(),
+            format!(r#"<{} xsi:nil="true" />"#, value.tag_name().name()),
+        );
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub(crate) fn unsupported(value: Node<'_, '_>, reason: &'static str) -> Error {
+    Error::at(value, ErrorKind::Unsupported(reason))
+}
+
+/// Write the fields of a value into the element that it was originally loaded from.
+pub(crate) trait Emit {
+    fn emit(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()>;
+}
+
+/// Counterpart of [`FromElement`] that patches the element a value was loaded from, or creates a
+/// new one if the value wasn't part of the original document.
+pub(crate) trait ToElement {
+    fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()>;
+
+    /// Create a new element named `tag`. The `parent` is only used for error reporting.
+    fn create(&self, parent: Node<'_, '_>, tag: &str) -> Result<String>;
+}
+
+/// Scalar values are only rewritten if they changed, so the original formatting of numbers is
+/// kept for everything else.
+macro_rules! impl_to_element_for_scalars {
+    ($($ty:ty),+) => {
+        $(
+            impl ToElement for $ty {
+                fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+                    let unchanged = value
+                        .text()
+                        .unwrap_or_default()
+                        .parse::<Self>()
+                        .map_or(false, |old| old == *self);
+
+                    if !unchanged {
+                        p.set_inner(value, escape(&self.to_string()));
+                    }
+
+                    Ok(())
+                }
+
+                fn create(&self, _parent: Node<'_, '_>, tag: &str) -> Result<String> {
+                    Ok(format!("<{0}>{1}</{0}>", tag, escape(&self.to_string())))
+                }
+            }
+        )+
+    };
+}
+
+impl_to_element_for_scalars!(
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    bool,
+    String,
+    ItemId,
+    Season,
+    Quality,
+    PreserveType,
+    FriendshipStatus,
+    GameVersion
+);
+
+macro_rules! impl_to_element_for_nodes {
+    ($($ty:ty),+) => {
+        $(
+            impl ToElement for $ty {
+                fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+                    self.emit(p, value)
+                }
+
+                fn create(&self, parent: Node<'_, '_>, _tag: &str) -> Result<String> {
+                    Err(unsupported(parent, "new entries of complex values"))
+                }
+            }
+        )+
+    };
+}
+
+impl_to_element_for_nodes!(
+    Color,
+    ClothingItem,
+    Stats,
+    Player,
+    FishCaught,
+    Friendship,
+    FarmerPair,
+    WorldDate,
+    Item
+);
+
+/// Positions are the keys of everything placed on a map, like museum pieces.
+impl ToElement for Position {
+    fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        self.emit(p, value)
+    }
+
+    fn create(&self, _parent: Node<'_, '_>, tag: &str) -> Result<String> {
+        Ok(format!(
+            "<{0}><X>{1}</X><Y>{2}</Y></{0}>",
+            tag, self.x, self.y
+        ))
+    }
+}
+
+/// Raw elements replace the original element as a whole, but only if they were changed.
+impl ToElement for RawXml {
+    fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        if p.source[value.range()] != *self.as_str() {
+            p.replace(value.range(), self.0.clone());
+        }
+
+        Ok(())
+    }
+
+    fn create(&self, _parent: Node<'_, '_>, _tag: &str) -> Result<String> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Values are patched by their position. Values past the end of the original list are removed,
+/// or appended with the element name of the existing ones.
+impl<T: ToElement> ToElement for Vec<T> {
+    fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        let children = value
+            .children()
+            .filter(|c| c.is_element())
+            .collect::<Vec<_>>();
+
+        for (child, item) in children.iter().zip(self) {
+            item.patch(p, *child)?;
+        }
+        for child in children.iter().skip(self.len()) {
+            p.remove(*child);
+        }
+
+        if self.len() > children.len() {
+            let tag = match children.last() {
+                Some(last) => last.tag_name().name(),
+                None => return Err(unsupported(value, "new values in an empty list")),
+            };
+            let new = self[children.len()..]
+                .iter()
+                .map(|item| item.create(value, tag))
+                .collect::<Result<Vec<_>>>()?;
+
+            p.append(value, &new);
+        }
+
+        Ok(())
+    }
+
+    fn create(&self, parent: Node<'_, '_>, _tag: &str) -> Result<String> {
+        Err(unsupported(parent, "new entries of complex values"))
+    }
+}
+
+impl<K, V> ToElement for BTreeMap<K, V>
+where
+    K: FromElement + ToElement + PartialEq,
+    V: ToElement,
+{
+    fn patch(&self, p: &mut Patcher<'_>, value: Node<'_, '_>) -> Result<()> {
+        patch_dict(p, value, None, self)
+    }
+
+    fn create(&self, parent: Node<'_, '_>, _tag: &str) -> Result<String> {
+        Err(unsupported(parent, "new entries of complex values"))
+    }
+}
+
+pub(crate) fn set<T: ToElement>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &T,
+) -> Result<()> {
+    field.patch(p, get(value, name)?)
+}
+
+pub(crate) fn set_wrapped<T: ToElement>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &T,
+) -> Result<()> {
+    let node = get(value, name)?;
+    let child = node
+        .first_element_child()
+        .ok_or_else(|| Error::at(node, ErrorKind::Empty))?;
+
+    field.patch(p, child)
+}
+
+/// Counterpart of `get_raw`, where an empty value stands for an element that didn't exist in
+/// the original save.
+pub(crate) fn set_raw(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &RawXml,
+) -> Result<()> {
+    match get_optional(value, name) {
+        Some(node) => field.patch(p, node),
+        None if field.is_empty() => Ok(()),
+        None => Err(unsupported(
+            value,
+            "an element that didn't exist in the original save",
+        )),
+    }
+}
+
+/// Values that were removed are turned into `xsi:nil` elements, but values can't be added as
+/// there is no original element they could be based on.
+pub(crate) fn set_optional<T: Emit>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &Option<T>,
+) -> Result<()> {
+    let node = get_optional(value, name).filter(|node| !is_nil(*node));
+
+    match (node, field) {
+        (Some(node), Some(field)) => field.emit(p, node),
+        (Some(node), None) => {
+            p.set_nil(node);
+            Ok(())
+        }
+        (None, Some(_)) => Err(unsupported(
+            get_optional(value, name).unwrap_or(value),
+            "a value that was empty in the original save",
+        )),
+        (None, None) => Ok(()),
+    }
+}
+
+/// Counterpart of `get_optional_string`, where empty elements stand for a missing text.
+pub(crate) fn set_optional_text(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: Option<&str>,
+) -> Result<()> {
+    match (get_optional(value, name), field) {
+        (Some(node), Some(text)) if is_nil(node) => {
+            p.replace(node.range(), text.to_owned().create(value, name)?);
+            Ok(())
+        }
+        (Some(node), Some(text)) => {
+            if node.text() != Some(text) {
+                p.set_inner(node, escape(text));
+            }
+            Ok(())
+        }
+        (Some(node), None) => {
+            if node.text().is_some() {
+                p.set_inner(node, String::new());
+            }
+            Ok(())
+        }
+        (None, Some(_)) => Err(unsupported(
+            value,
+            "a value that was empty in the original save",
+        )),
+        (None, None) => Ok(()),
+    }
+}
+
+/// Some optional values are left out of the save entirely when they are empty, instead of being
+/// written as `xsi:nil`. New values are inserted in front of the element named `next`, which
+/// always follows them.
+pub(crate) fn set_omitted<T: ToElement>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    next: &str,
+    field: &Option<T>,
+) -> Result<()> {
+    match (get_optional(value, name), field) {
+        (Some(node), Some(field)) if is_nil(node) => {
+            p.replace(node.range(), field.create(value, name)?);
+            Ok(())
+        }
+        (Some(node), Some(field)) => field.patch(p, node),
+        (Some(node), None) => {
+            if !is_nil(node) {
+                p.remove(node);
+            }
+            Ok(())
+        }
+        (None, Some(field)) => {
+            let element = field.create(value, name)?;
+            p.insert_before(get(value, next)?, &element);
+            Ok(())
+        }
+        (None, None) => Ok(()),
+    }
+}
+
+/// Lists of complex values are patched entry by entry. Entries can be removed, but not added, as
+/// there is no original element new entries could be based on.
+pub(crate) fn set_list<T: Emit>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &[T],
+) -> Result<()> {
+    patch_list(p, get(value, name)?, field, false)
+}
+
+/// Like [`set_list`], but for lists of slots such as the inventory, where removed entries leave
+/// an empty `xsi:nil` slot behind instead of shifting the following entries.
+pub(crate) fn set_slot_list<T: Emit>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &[T],
+) -> Result<()> {
+    patch_list(p, get(value, name)?, field, true)
+}
+
+fn patch_list<T: Emit>(
+    p: &mut Patcher<'_>,
+    list: Node<'_, '_>,
+    field: &[T],
+    slots: bool,
+) -> Result<()> {
+    let children = list
+        .children()
+        .filter(|c| c.is_element() && !is_nil(*c))
+        .collect::<Vec<_>>();
+
+    if field.len() > children.len() {
+        return Err(unsupported(list, "new entries of complex values"));
+    }
+
+    for (child, item) in align(p, &children, field)? {
+        match item {
+            Some(item) => item.emit(p, child)?,
+            None if slots => p.set_nil(child),
+            None => p.remove(child),
+        }
+    }
+
+    Ok(())
+}
+
+/// Match the entries of a list to the original elements after some of them were removed. Every
+/// entry is matched to the original element it differs least from, while keeping their order.
+fn align<'a, 'n, T: Emit>(
+    p: &Patcher<'_>,
+    children: &[Node<'n, 'n>],
+    field: &'a [T],
+) -> Result<Vec<(Node<'n, 'n>, Option<&'a T>)>> {
+    if children.len() == field.len() {
+        return Ok(children
+            .iter()
+            .copied()
+            .zip(field.iter().map(Some))
+            .collect());
+    }
+
+    // Number of patches needed to turn the original element into the entry.
+    let changes = |child: Node<'_, '_>, item: &T| {
+        let mut scratch = p.scratch();
+        item.emit(&mut scratch, child)
+            .ok()
+            .map(|()| scratch.patches.len())
+    };
+
+    // The unchanged start and end of the list don't need to be lined up.
+    let start = children
+        .iter()
+        .zip(field)
+        .take_while(|(child, item)| changes(**child, item) == Some(0))
+        .count();
+    let end = children[start..]
+        .iter()
+        .rev()
+        .zip(field[start..].iter().rev())
+        .take_while(|(child, item)| changes(**child, item) == Some(0))
+        .count();
+    let (rest, rest_field) = (
+        &children[start..children.len() - end],
+        &field[start..field.len() - end],
+    );
+
+    // Fewest changes to line up the rest, for every pair of remaining elements and entries.
+    let (n, m) = (rest.len(), rest_field.len());
+    let mut fewest = vec![vec![None; m + 1]; n + 1];
+    fewest[n][m] = Some(0);
+    for i in (0..n).rev() {
+        for j in (0..=m).rev() {
+            let removed = fewest[i + 1][j].filter(|_| n - i > m - j);
+            let kept = rest_field
+                .get(j)
+                .and_then(|item| Some(changes(rest[i], item)? + fewest[i + 1][j + 1]?));
+
+            fewest[i][j] = match (removed, kept) {
+                (Some(removed), Some(kept)) => Some(removed.min(kept)),
+                (removed, kept) => removed.or(kept),
+            };
+        }
+    }
+
+    if fewest[0][0].is_none() {
+        return Err(unsupported(
+            rest[0],
+            "entries that don't match any of the original ones",
+        ));
+    }
+
+    let mut aligned = children[..start]
+        .iter()
+        .copied()
+        .zip(field[..start].iter().map(Some))
+        .collect::<Vec<_>>();
+    let mut j = 0;
+    for i in 0..n {
+        let removed = fewest[i + 1][j].filter(|_| n - i > m - j);
+        if removed.is_some() && removed == fewest[i][j] {
+            aligned.push((rest[i], None));
+        } else {
+            aligned.push((rest[i], Some(&rest_field[j])));
+            j += 1;
+        }
+    }
+    aligned.extend(
+        children[children.len() - end..]
+            .iter()
+            .copied()
+            .zip(field[field.len() - end..].iter().map(Some)),
+    );
+
+    Ok(aligned)
+}
+
+pub(crate) fn set_optional_list<T: Emit>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    field: &[T],
+) -> Result<()> {
+    if get_optional(value, name).is_none() && field.is_empty() {
+        return Ok(());
+    }
+
+    set_list(p, value, name, field)
+}
+
+/// Lists of simple values are rewritten as a whole if any of their entries changed.
+pub(crate) fn set_text_list<T>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    tag: &str,
+    field: &[T],
+) -> Result<()>
+where
+    T: Display + FromStr + PartialEq,
+{
+    let list = get(value, name)?;
+    let children = list.children().filter(|c| c.is_element());
+    let unchanged = children.clone().count() == field.len()
+        && children.zip(field).all(|(child, item)| {
+            child.text().unwrap_or_default().parse::<T>().ok().as_ref() == Some(item)
+        });
+
+    if !unchanged {
+        let (indent, end) = indentation(list);
+        let mut content = field
+            .iter()
+            .map(|item| format!("{0}<{1}>{2}</{1}>", indent, tag, escape(&item.to_string())))
+            .collect::<String>();
+        content.push_str(&end);
+        p.set_inner(list, content);
+    }
+
+    Ok(())
+}
+
+/// Whitespace in front of each child of a list and in front of its end tag. Lists that didn't
+/// have any children yet are indented one level deeper than the list itself.
+fn indentation(list: Node<'_, '_>) -> (String, String) {
+    let whitespace = |node: Option<Node<'_, '_>>| {
+        node.filter(|n| n.is_text())
+            .and_then(|n| n.text())
+            .filter(|text| text.trim().is_empty())
+            .map(ToOwned::to_owned)
+    };
+
+    let end = match list.last_child() {
+        Some(last) => whitespace(Some(last)),
+        None => whitespace(list.prev_sibling()),
+    }
+    .unwrap_or_default();
+    let indent = whitespace(list.first_child())
+        .filter(|_| list.first_element_child().is_some())
+        .unwrap_or_else(|| {
+            if end.is_empty() {
+                String::new()
+            } else {
+                format!("{}  ", end)
+            }
+        });
+
+    (indent, end)
+}
+
+/// Patch a serializable dictionary. Entries are matched by their key, so removed entries are
+/// dropped and new ones appended. New entries take their element names from existing ones, or
+/// from `tags` if the dictionary was empty.
+pub(crate) fn set_dict<'v, K, V>(
+    p: &mut Patcher<'_>,
+    value: Node<'_, '_>,
+    name: &str,
+    tags: (&str, &str),
+    entries: impl IntoIterator<Item = (&'v K, &'v V)>,
+) -> Result<()>
+where
+    K: FromElement + ToElement + PartialEq + 'v,
+    V: ToElement + 'v,
+{
+    patch_dict(p, get(value, name)?, Some(tags), entries)
+}
+
+fn patch_dict<'v, K, V>(
+    p: &mut Patcher<'_>,
+    dict: Node<'_, '_>,
+    tags: Option<(&str, &str)>,
+    entries: impl IntoIterator<Item = (&'v K, &'v V)>,
+) -> Result<()>
+where
+    K: FromElement + ToElement + PartialEq + 'v,
+    V: ToElement + 'v,
+{
+    let mut entries = entries.into_iter().map(Some).collect::<Vec<_>>();
+    let mut tags = tags.map(|(key, value)| (key.to_owned(), value.to_owned()));
+
+    for item in dict.children().filter(|c| c.is_element()) {
+        let key_node = first_child(get(item, "key")?)?;
+        let value_node = first_child(get(item, "value")?)?;
+        let key = K::from_element(key_node)?;
+
+        tags.get_or_insert_with(|| {
+            (
+                key_node.tag_name().name().to_owned(),
+                value_node.tag_name().name().to_owned(),
+            )
+        });
+
+        match entries
+            .iter_mut()
+            .find(|entry| matches!(entry, Some((k, _)) if **k == key))
+            .and_then(Option::take)
+        {
+            Some((_, v)) => v.patch(p, value_node)?,
+            None => p.remove(item),
+        }
+    }
+
+    let (key_tag, value_tag) = match tags {
+        Some(tags) => tags,
+        None if entries.is_empty() => return Ok(()),
+        None => return Err(unsupported(dict, "new entries in an empty dictionary")),
+    };
+
+    let new = entries
+        .into_iter()
+        .flatten()
+        .map(|(k, v)| {
+            Ok(format!(
+                "<item><key>{}</key><value>{}</value></item>",
+                k.create(dict, &key_tag)?,
+                v.create(dict, &value_tag)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if !new.is_empty() {
+        p.append(dict, &new);
+    }
+
+    Ok(())
+}
+
+fn first_child<'a>(value: Node<'a, 'a>) -> Result<Node<'a, 'a>> {
+    value
+        .first_element_child()
+        .ok_or_else(|| Error::at(value, ErrorKind::Empty))
+}
@@ -0,0 +1,147 @@
+use std::{
+    cell::Cell,
+    fmt::{self, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use roxmltree::Node;
+
+use crate::{
+    de::{get_optional, parse_content},
+    error::Result,
+};
+
+/// Version of the game that wrote a save, like `1.5.6`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl GameVersion {
+    pub const V1_2: Self = Self::new(1, 2, 0);
+    pub const V1_3: Self = Self::new(1, 3, 0);
+    pub const V1_4: Self = Self::new(1, 4, 0);
+    pub const V1_5: Self = Self::new(1, 5, 0);
+    pub const V1_6: Self = Self::new(1, 6, 0);
+
+    const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Saves only carry a `gameVersion` since 1.4, so older ones are recognized by the update
+    /// flags the game added along the way.
+    pub(crate) fn detect(value: Node<'_, '_>) -> Result<Self> {
+        if let Some(node) = get_optional(value, "gameVersion") {
+            return parse_content(node);
+        }
+
+        Ok(
+            if get_optional(value, "hasApplied1_3_UpdateChanges").is_some() {
+                Self::V1_3
+            } else {
+                Self::V1_2
+            },
+        )
+    }
+}
+
+impl Default for GameVersion {
+    fn default() -> Self {
+        Self::V1_5
+    }
+}
+
+impl FromStr for GameVersion {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('.').map(str::parse).chain(std::iter::repeat(Ok(0)));
+
+        Ok(Self::new(
+            parts.next().unwrap_or(Ok(0))?,
+            parts.next().unwrap_or(Ok(0))?,
+            parts.next().unwrap_or(Ok(0))?,
+        ))
+    }
+}
+
+impl Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GameVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GameVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'_, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+thread_local! {
+    static VERSION: Cell<GameVersion> = const { Cell::new(GameVersion::V1_5) };
+}
+
+/// Sets the version of the save that is currently parsed, restoring the previous one when dropped.
+pub(crate) struct VersionGuard(GameVersion);
+
+impl VersionGuard {
+    pub(crate) fn new(version: GameVersion) -> Self {
+        Self(VERSION.with(|v| v.replace(version)))
+    }
+}
+
+impl Drop for VersionGuard {
+    fn drop(&mut self) {
+        VERSION.with(|v| v.set(self.0));
+    }
+}
+
+pub(crate) fn version() -> GameVersion {
+    VERSION.with(Cell::get)
+}
+
+/// Only parses the value if the current save is from the given version or later, as older saves
+/// don't contain the element at all.
+pub(crate) fn since<T>(min: GameVersion, parse: impl FnOnce() -> Result<T>) -> Result<Option<T>> {
+    if version() >= min {
+        parse().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Only parses the value if the current save is older than the given version, as the element was
+/// dropped from the save in that version.
+pub(crate) fn until<T>(max: GameVersion, parse: impl FnOnce() -> Result<T>) -> Result<Option<T>> {
+    if version() < max {
+        parse().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Events, dialogue answers and items were identified by numbers up to 1.5, but are strings since
+/// 1.6.
+pub(crate) fn id_tag() -> &'static str {
+    if version() >= GameVersion::V1_6 {
+        "string"
+    } else {
+        "int"
+    }
+}
@@ -0,0 +1,108 @@
+use stardew_save::{Room, RoomState};
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn restored_rooms_need_nothing() {
+    let save_game = stardew_save::load(SAVE).unwrap();
+    let bundles = stardew_save::bundles(&save_game);
+
+    let pantry = &bundles.rooms[0];
+    assert_eq!(Room::Pantry, pantry.room);
+    assert_eq!(RoomState::Restored, pantry.state);
+    assert_eq!(1, pantry.completed_bundles());
+    assert_eq!(0, bundles.still_needed().count());
+}
+
+#[test]
+fn missing_items_are_listed() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game
+        .player
+        .mail_received
+        .retain(|mail| mail != "ccPantry");
+    for location in &mut save_game.locations {
+        if let Some(cc) = &mut location.community_center {
+            cc.areas_complete.clear();
+        }
+    }
+
+    let bundles = stardew_save::bundles(&save_game);
+    let missing = bundles
+        .still_needed()
+        .map(|(_, bundle, item)| format!("{}: {}", bundle.name, item))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            "Summer Crops: Hot Pepper",
+            "Summer Crops: Blueberry",
+            "Summer Crops: Melon",
+        ],
+        missing
+    );
+}
+
+#[test]
+fn joja_members_need_nothing() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    for location in &mut save_game.locations {
+        if let Some(cc) = &mut location.community_center {
+            cc.areas_complete.clear();
+        }
+    }
+    save_game
+        .player
+        .mail_received
+        .retain(|mail| mail != "ccPantry");
+    save_game.player.mail_received.push("JojaMember".to_owned());
+
+    let bundles = stardew_save::bundles(&save_game);
+
+    assert!(bundles.joja_member);
+    assert_eq!(RoomState::Incomplete, bundles.rooms[0].state);
+    assert_eq!(0, bundles.still_needed().count());
+}
+
+#[test]
+fn abandoned_joja_mart_follows_the_movie_theater() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game.bundle_data.clear();
+    let state = |save_game: &stardew_save::SaveGame| {
+        stardew_save::bundles(save_game)
+            .rooms
+            .iter()
+            .find(|room| room.room == Room::AbandonedJojaMart)
+            .unwrap()
+            .state
+    };
+
+    assert_eq!(RoomState::Incomplete, state(&save_game));
+    save_game
+        .player
+        .mail_received
+        .push("ccMovieTheaterJoja".to_owned());
+    assert_eq!(RoomState::Purchased, state(&save_game));
+    save_game
+        .player
+        .mail_received
+        .push("ccMovieTheater".to_owned());
+    assert_eq!(RoomState::Restored, state(&save_game));
+}
+
+#[test]
+fn vanilla_bundles_are_used_without_bundle_data() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game.bundle_data.clear();
+
+    let bundles = stardew_save::bundles(&save_game);
+    let vault = bundles
+        .rooms
+        .iter()
+        .find(|room| room.room == Room::Vault)
+        .unwrap();
+
+    assert_eq!(7, bundles.rooms.len());
+    assert_eq!(4, vault.bundles.len());
+    assert_eq!("2500g", vault.bundles[0].items[0].to_string());
+}
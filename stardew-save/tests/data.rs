@@ -0,0 +1,26 @@
+use stardew_save::{Category, GameData, ItemId, Season};
+
+#[test]
+fn items_are_resolved() {
+    let data = GameData::get();
+
+    let parsnip = data.object(24).unwrap();
+    assert_eq!("Parsnip", parsnip.name);
+    assert_eq!(35, parsnip.price);
+    assert_eq!(Category::Vegetable, parsnip.category);
+
+    let keg = "(BC)12".parse::<ItemId>().unwrap();
+    assert_eq!("Keg", data.item(&keg).unwrap().name);
+
+    // Only objects and big craftables are bundled.
+    let hat = "(H)2".parse::<ItemId>().unwrap();
+    assert_eq!(None, data.item(&hat));
+}
+
+#[test]
+fn villagers_are_resolved() {
+    let abigail = GameData::get().npc("Abigail").unwrap();
+
+    assert!(abigail.datable);
+    assert_eq!((Season::Fall, 13), abigail.birthday);
+}
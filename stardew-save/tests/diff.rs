@@ -0,0 +1,60 @@
+use stardew_save::{Change, ChangeKind};
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn same_save_has_no_changes() {
+    let save_game = stardew_save::load(SAVE).unwrap();
+
+    assert!(stardew_save::diff(&save_game, &save_game).is_empty());
+}
+
+#[test]
+fn changes_are_reported() {
+    let old = stardew_save::load(SAVE).unwrap();
+    let mut new = stardew_save::load(SAVE).unwrap();
+    new.player.money = old.player.money + 250;
+    new.player.mail_received.push("newMail".to_owned());
+
+    let changes = stardew_save::diff(&old, &new);
+
+    assert_eq!(
+        vec![
+            Change {
+                farmer: old.player.name.clone(),
+                field: "money".to_owned(),
+                kind: ChangeKind::Changed {
+                    old: old.player.money.to_string(),
+                    new: new.player.money.to_string(),
+                },
+            },
+            Change {
+                farmer: old.player.name.clone(),
+                field: "mail_received".to_owned(),
+                kind: ChangeKind::Added("newMail".to_owned()),
+            },
+        ],
+        changes
+    );
+}
+
+#[test]
+fn renamed_farmers_are_not_added_or_removed() {
+    let old = stardew_save::load(SAVE).unwrap();
+    let mut new = stardew_save::load(SAVE).unwrap();
+    new.player.name = "Renamed".to_owned();
+
+    let changes = stardew_save::diff(&old, &new);
+
+    assert_eq!(
+        vec![Change {
+            farmer: "Renamed".to_owned(),
+            field: "name".to_owned(),
+            kind: ChangeKind::Changed {
+                old: old.player.name.clone(),
+                new: "Renamed".to_owned(),
+            },
+        }],
+        changes
+    );
+}
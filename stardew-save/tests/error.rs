@@ -0,0 +1,32 @@
+use stardew_save::ErrorKind;
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn error_points_at_the_element() {
+    let broken = SAVE.replacen("<Stack>3</Stack>", "<Stack>three</Stack>", 1);
+
+    let error = stardew_save::load(&broken).unwrap_err();
+
+    assert!(matches!(error.kind, ErrorKind::Invalid { .. }));
+    assert_eq!("SaveGame/player/items/Item[3]/Stack", error.path);
+    assert_eq!((265, 9), (error.pos.row, error.pos.col));
+    assert_eq!(
+        "SaveGame/player/items/Item[3]/Stack at 265:9: invalid content `three`: invalid digit \
+         found in string",
+        error.to_string()
+    );
+}
+
+#[test]
+fn missing_element_is_named_in_path() {
+    let broken = SAVE
+        .replacen("<money>", "<cash>", 1)
+        .replacen("</money>", "</cash>", 1);
+
+    let error = stardew_save::load(&broken).unwrap_err();
+
+    assert!(matches!(error.kind, ErrorKind::Missing));
+    assert_eq!("SaveGame/player/money", error.path);
+    assert_eq!((3, 3), (error.pos.row, error.pos.col));
+}
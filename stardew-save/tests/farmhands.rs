@@ -0,0 +1,26 @@
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn blank_cabin_farmhands_are_skipped() {
+    let save_game = stardew_save::load(SAVE).unwrap();
+
+    let names = save_game
+        .farmers()
+        .map(|farmer| farmer.name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["Alex", "Sam"], names);
+}
+
+#[test]
+fn farmhands_are_written_back() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game.farmhands[0].money += 500;
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+
+    assert_eq!(save_game.farmhands[0].money, reloaded.farmhands[0].money);
+    assert_eq!(save_game.player.money, reloaded.player.money);
+    assert!(written.contains("<uniqueName>Cabin7c21</uniqueName>"));
+}
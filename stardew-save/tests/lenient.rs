@@ -0,0 +1,51 @@
+use stardew_save::ErrorKind;
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn broken_elements_become_warnings() {
+    let broken = SAVE
+        .replacen("<Stack>3</Stack>", "<Stack>three</Stack>", 1)
+        .replacen("<money>", "<cash>", 1)
+        .replacen("</money>", "</cash>", 1);
+
+    assert!(stardew_save::load(&broken).is_err());
+
+    let (save_game, warnings) = stardew_save::load_lenient(&broken).unwrap();
+
+    assert_eq!(0, save_game.player.money);
+    assert_eq!(0, save_game.player.items[3].stack);
+    assert_eq!(2, warnings.len());
+    assert!(matches!(warnings[0].kind, ErrorKind::Invalid { .. }));
+    assert_eq!("SaveGame/player/items/Item[3]/Stack", warnings[0].path);
+    assert!(matches!(warnings[1].kind, ErrorKind::Missing));
+    assert_eq!("SaveGame/player/money", warnings[1].path);
+}
+
+#[test]
+fn valid_save_has_no_warnings() {
+    let (save_game, warnings) = stardew_save::load_lenient(SAVE).unwrap();
+
+    assert!(warnings.is_empty());
+    assert_eq!(
+        stardew_save::load(SAVE).unwrap().player.money,
+        save_game.player.money
+    );
+}
+
+#[test]
+fn saves_with_warnings_are_not_written() {
+    let broken = SAVE.replacen(
+        "<currentSeason>spring</currentSeason>",
+        "<currentSeason>autumn</currentSeason>",
+        1,
+    );
+
+    let (save_game, warnings) = stardew_save::load_lenient(&broken).unwrap();
+    assert_eq!(1, warnings.len());
+
+    let error = stardew_save::write(&save_game, &broken).unwrap_err();
+
+    assert!(matches!(error.kind, ErrorKind::Invalid { .. }));
+    assert_eq!("SaveGame/currentSeason", error.path);
+}
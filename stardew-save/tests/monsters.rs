@@ -0,0 +1,25 @@
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn kills_count_towards_goals() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let kills = &mut save_game.player.stats.specific_monsters_killed;
+    kills.insert("Green Slime".to_owned(), 600);
+    kills.insert("Frost Jelly".to_owned(), 400);
+    kills.insert("Duggy".to_owned(), 12);
+    save_game
+        .player
+        .mail_received
+        .push("Gil_Slime Charmer Ring".to_owned());
+
+    let goals = stardew_save::monster_goals(&save_game.player);
+    let slimes = goals.iter().find(|goal| goal.name == "Slimes").unwrap();
+    let duggies = goals.iter().find(|goal| goal.name == "Duggies").unwrap();
+
+    assert!(slimes.kills() >= 1000);
+    assert!(slimes.is_complete());
+    assert!(slimes.reward_claimed);
+    assert!(!slimes.reward_available());
+    assert!(duggies.kills() >= 12);
+    assert!(!duggies.is_complete());
+}
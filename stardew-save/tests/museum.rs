@@ -0,0 +1,72 @@
+use stardew_save::{DonationState, ItemId, Position};
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn donations_are_split_by_state() {
+    let save_game = stardew_save::load(SAVE).unwrap();
+    let donations = stardew_save::donations(&save_game);
+
+    let names = |state| {
+        donations
+            .with_state(state)
+            .map(|item| item.name.as_str())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(42, donations.artifacts.len());
+    assert_eq!(53, donations.minerals.len());
+    assert_eq!(
+        vec!["Dwarf Scroll I", "Quartz"],
+        names(DonationState::Donated)
+    );
+    assert_eq!(
+        vec!["Ancient Doll", "Earth Crystal"],
+        names(DonationState::Found)
+    );
+    assert!(donations
+        .milestones
+        .iter()
+        .all(|milestone| !milestone.reached));
+}
+
+#[test]
+fn milestones_are_reached_by_donation_count() {
+    let reached = |count: usize| {
+        let mut save_game = stardew_save::load(SAVE).unwrap();
+        let indices = stardew_save::donations(&save_game)
+            .items()
+            .map(|item| item.index)
+            .take(count)
+            .collect::<Vec<_>>();
+        let museum = save_game
+            .locations
+            .iter_mut()
+            .find_map(|location| location.museum.as_mut())
+            .unwrap();
+        museum.museum_pieces = indices
+            .into_iter()
+            .enumerate()
+            .map(|(i, index)| {
+                let tile = Position {
+                    x: i as f64,
+                    y: 0.0,
+                };
+                (tile, ItemId::from(index))
+            })
+            .collect();
+
+        stardew_save::donations(&save_game)
+            .milestones
+            .into_iter()
+            .filter(|milestone| milestone.reached)
+            .map(|milestone| milestone.reward)
+            .collect::<Vec<_>>()
+    };
+
+    assert!(reached(19).is_empty());
+    assert_eq!(vec!["Rarecrow #7"], reached(20));
+    assert_eq!(vec!["Rarecrow #7", "Rarecrow #8"], reached(59));
+    assert_eq!(vec!["Rarecrow #7", "Rarecrow #8", "Rusty Key"], reached(94));
+    assert_eq!(4, reached(95).len());
+}
@@ -0,0 +1,88 @@
+use stardew_save::Goal;
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn score_adds_up_goals() {
+    let save_game = stardew_save::load(SAVE).unwrap();
+    let perfection = stardew_save::perfection(&save_game);
+
+    let walnuts = perfection
+        .goals
+        .iter()
+        .find(|progress| progress.goal == Goal::GoldenWalnuts)
+        .unwrap();
+    assert_eq!(12, walnuts.done);
+    assert_eq!(vec!["118 more golden walnuts"], walnuts.missing);
+
+    let sum = perfection
+        .goals
+        .iter()
+        .map(|progress| progress.score())
+        .sum::<f64>();
+    assert!(sum > 0.0 && sum < 100.0);
+    assert_eq!(sum, perfection.percent());
+}
+
+#[test]
+fn unfinished_all_or_nothing_goals_score_nothing() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game.player.mail_received.push("CF_Fair".to_owned());
+
+    let perfection = stardew_save::perfection(&save_game);
+    let stardrops = perfection
+        .goals
+        .iter()
+        .find(|progress| progress.goal == Goal::Stardrops)
+        .unwrap();
+
+    assert!(stardrops.done > 0);
+    assert_eq!(0.0, stardrops.score());
+}
+
+#[test]
+fn collections_count_the_best_farmer() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game.player.basic_shipped.clear();
+    save_game
+        .player
+        .basic_shipped
+        .insert("24".parse().unwrap(), 1);
+    save_game
+        .player
+        .basic_shipped
+        .insert("188".parse().unwrap(), 1);
+    save_game.farmhands[0].basic_shipped.clear();
+    save_game.farmhands[0]
+        .basic_shipped
+        .insert("190".parse().unwrap(), 1);
+
+    let perfection = stardew_save::perfection(&save_game);
+    let shipped = perfection
+        .goals
+        .iter()
+        .find(|progress| progress.goal == Goal::ShippedItems)
+        .unwrap();
+
+    assert_eq!(2, shipped.done);
+    assert!(shipped.missing.iter().any(|name| name == "Cauliflower"));
+}
+
+#[test]
+fn weights_add_up_to_a_hundred() {
+    let goals = [
+        Goal::ShippedItems,
+        Goal::Obelisks,
+        Goal::GoldClock,
+        Goal::MonsterSlayer,
+        Goal::GreatFriends,
+        Goal::FarmerLevel,
+        Goal::Stardrops,
+        Goal::Cooking,
+        Goal::Crafting,
+        Goal::Fish,
+        Goal::GoldenWalnuts,
+    ];
+
+    assert_eq!(100.0, goals.iter().map(|goal| goal.weight()).sum::<f64>());
+}
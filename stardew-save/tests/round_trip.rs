@@ -0,0 +1,195 @@
+use stardew_save::{ErrorKind, Item, ItemId, ItemType, Position, PreserveType, Quest, SaveGame};
+
+const SAVE: &str = include_str!("fixtures/save.xml");
+
+#[test]
+fn unchanged_save_is_identical() {
+    let save_game = stardew_save::load(SAVE).unwrap();
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+
+    assert_eq!(SAVE, written);
+}
+
+#[test]
+fn unmodeled_elements_are_kept() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game.player.money += 1000;
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+
+    assert_eq!(save_game.player.money, reloaded.player.money);
+    assert_eq!(save_game.custom_data, reloaded.custom_data);
+    assert_eq!(save_game.dis_of_the_day, reloaded.dis_of_the_day);
+    assert_eq!(
+        save_game.player.mail_for_tomorrow,
+        reloaded.player.mail_for_tomorrow
+    );
+    assert!(reloaded.custom_data.as_str().contains("smapi/mod-data"));
+}
+
+#[test]
+fn changed_raw_elements_are_written_verbatim() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let mail = "<mailForTomorrow><string>Robin</string></mailForTomorrow>";
+    save_game.player.mail_for_tomorrow = stardew_save::RawXml(mail.to_owned());
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+
+    assert_eq!(mail, reloaded.player.mail_for_tomorrow.as_str());
+    assert_eq!(1, written.matches(mail).count());
+}
+
+#[test]
+fn removed_items_leave_an_empty_slot() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let removed = save_game.player.items.remove(1);
+    let last = save_game.player.items.len() - 1;
+    save_game.player.items[last].stack += 1;
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+    let names = |items: &[Item]| {
+        items
+            .iter()
+            .map(|item| item.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        names(&save_game.player.items),
+        names(&reloaded.player.items)
+    );
+    assert_eq!(
+        save_game.player.items[last].stack,
+        reloaded.player.items[last].stack
+    );
+    assert_eq!(
+        SAVE.matches(r#"<Item xsi:nil="true" />"#).count() + 1,
+        written.matches(r#"<Item xsi:nil="true" />"#).count()
+    );
+    assert!(!names(&reloaded.player.items).contains(&removed.name));
+}
+
+#[test]
+fn new_entries_of_complex_values_are_an_error() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let item = save_game.player.items.remove(0);
+    save_game.player.items_lost_last_death.push(item);
+
+    let error = stardew_save::write(&save_game, SAVE).unwrap_err();
+
+    assert!(matches!(error.kind, ErrorKind::Unsupported(_)));
+    assert_eq!("SaveGame/player/itemsLostLastDeath", error.path);
+}
+
+#[test]
+fn location_changes_are_written() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let farm = &mut save_game.locations[0];
+    farm.buildings[0].tile_x += 2;
+    farm.objects.remove(0);
+    farm.characters[0].name = "Whiskers".to_owned();
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+    let (farm, reloaded_farm) = (&save_game.locations[0], &reloaded.locations[0]);
+
+    assert_eq!(farm.buildings[0].tile_x, reloaded_farm.buildings[0].tile_x);
+    assert_eq!(farm.objects.len(), reloaded_farm.objects.len());
+    assert_eq!(
+        farm.objects[0].object.name,
+        reloaded_farm.objects[0].object.name
+    );
+    assert_eq!("Whiskers", reloaded_farm.characters[0].name);
+}
+
+#[test]
+fn quest_changes_are_written() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let quests = &mut save_game.player.quest_log;
+    quests[0].days_left = 1;
+    quests[0].current_objective = "Bring Pierre two Parsnips".to_owned();
+    quests.remove(1);
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+    let titles = |quests: &[Quest]| {
+        quests
+            .iter()
+            .map(|q| q.quest_title.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        titles(&save_game.player.quest_log),
+        titles(&reloaded.player.quest_log)
+    );
+    assert_eq!(1, reloaded.player.quest_log[0].days_left);
+    assert_eq!(
+        "Bring Pierre two Parsnips",
+        reloaded.player.quest_log[0].current_objective
+    );
+}
+
+#[test]
+fn preserves_can_be_changed() {
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    let jelly = save_game
+        .player
+        .items
+        .iter()
+        .position(|item| item.name == "Jelly")
+        .unwrap();
+    let leek = save_game.locations[0]
+        .objects
+        .iter()
+        .position(|object| object.object.name == "Leek")
+        .unwrap();
+    let blueberry = match &mut save_game.player.items[jelly].ty {
+        ItemType::Object { preserve, .. } => preserve.take(),
+        _ => None,
+    };
+    if let ItemType::Object { preserve, .. } = &mut save_game.locations[0].objects[leek].object.ty {
+        *preserve = blueberry;
+    }
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+
+    assert!(matches!(
+        reloaded.player.items[jelly].ty,
+        ItemType::Object { preserve: None, .. }
+    ));
+    assert!(matches!(
+        &reloaded.locations[0].objects[leek].object.ty,
+        ItemType::Object { preserve: Some(preserve), .. }
+            if preserve.ty == PreserveType::Jelly && preserve.parent_sheet_index == 258
+    ));
+}
+
+#[test]
+fn museum_donations_are_added() {
+    let museum = |save_game: &SaveGame| {
+        save_game
+            .locations
+            .iter()
+            .find_map(|location| location.museum.as_ref())
+            .map(|museum| museum.museum_pieces.clone())
+            .unwrap()
+    };
+    let mut save_game = stardew_save::load(SAVE).unwrap();
+    save_game
+        .locations
+        .iter_mut()
+        .find_map(|location| location.museum.as_mut())
+        .unwrap()
+        .museum_pieces
+        .push((Position { x: 27.0, y: 5.0 }, ItemId::from(110)));
+
+    let written = stardew_save::write(&save_game, SAVE).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+
+    assert_eq!(museum(&save_game), museum(&reloaded));
+}
@@ -0,0 +1,90 @@
+use stardew_save::{GameVersion, Season};
+
+const SAVE_1_4: &str = include_str!("fixtures/save_1_4.xml");
+const SAVE_1_5: &str = include_str!("fixtures/save.xml");
+const SAVE_1_6: &str = include_str!("fixtures/save_1_6.xml");
+
+#[test]
+fn loads_1_4_save() {
+    let save_game = stardew_save::load(SAVE_1_4).unwrap();
+
+    assert!(save_game.game_version >= GameVersion::V1_4);
+    assert!(save_game.game_version < GameVersion::V1_5);
+    assert!(save_game.island.is_none());
+    assert!(save_game.bundle_data.is_empty());
+    assert_eq!(Some(true), save_game.player.cat_person);
+    assert_eq!(None, save_game.player.which_pet_type);
+    assert_eq!(vec!["60367", "112"], save_game.player.events_seen);
+}
+
+#[test]
+fn loads_1_6_save() {
+    let save_game = stardew_save::load(SAVE_1_6).unwrap();
+    let player = &save_game.player;
+
+    assert!(save_game.game_version >= GameVersion::V1_6);
+    assert_eq!(None, player.cat_person);
+    assert_eq!(Some("Cat"), player.which_pet_type.as_deref());
+    assert_eq!(None, player.has_rusty_key);
+    assert_eq!(vec!["60367", "112"], player.events_seen);
+    assert_eq!(vec!["62"], player.dialogue_questions_answered);
+    assert_eq!(120, player.stats.seeds_sown);
+    assert_eq!(0, player.stats.duck_eggs_layed);
+    assert!(player.items.iter().all(|item| item.item_id.is_some()));
+    assert_eq!(1, save_game.farmhands.len());
+}
+
+#[test]
+fn older_versions_keep_removed_fields() {
+    let save_game = stardew_save::load(SAVE_1_5).unwrap();
+
+    assert_eq!(Some(true), save_game.player.cat_person);
+    assert_eq!(Some(false), save_game.player.has_rusty_key);
+    assert_eq!(Some("1.5.6"), save_game.player.game_version.as_deref());
+}
+
+#[test]
+fn unchanged_saves_are_identical() {
+    for save in [SAVE_1_4, SAVE_1_6].iter() {
+        let save_game = stardew_save::load(save).unwrap();
+
+        assert_eq!(*save, stardew_save::write(&save_game, save).unwrap());
+    }
+}
+
+#[test]
+fn new_events_use_the_version_specific_tag() {
+    let mut save_game = stardew_save::load(SAVE_1_6).unwrap();
+    save_game.player.events_seen.push("MarnieCow".to_owned());
+
+    let written = stardew_save::write(&save_game, SAVE_1_6).unwrap();
+    let reloaded = stardew_save::load(&written).unwrap();
+
+    assert!(written.contains("<string>MarnieCow</string>"));
+    assert_eq!(save_game.player.events_seen, reloaded.player.events_seen);
+}
+
+#[test]
+fn fall_is_read_and_written_like_the_game() {
+    let save_game = stardew_save::load(SAVE_1_4).unwrap();
+    let gift_date = save_game
+        .player
+        .friendship_data
+        .values()
+        .find_map(|friendship| friendship.last_gift_date.as_ref())
+        .unwrap();
+
+    assert_eq!(Season::Fall, save_game.current_season);
+    assert_eq!(Season::Fall, gift_date.season);
+
+    let mut save_game = stardew_save::load(SAVE_1_5).unwrap();
+    save_game.current_season = Season::Fall;
+
+    let written = stardew_save::write(&save_game, SAVE_1_5).unwrap();
+
+    assert!(written.contains("<currentSeason>fall</currentSeason>"));
+    assert_eq!(
+        Season::Fall,
+        stardew_save::load(&written).unwrap().current_season
+    );
+}